@@ -0,0 +1,163 @@
+/*
+ * Opt-in allocation counting. Build with `--features count-allocations` to install
+ * `CountingAllocator` as the global allocator; `run_part` then reports allocation
+ * statistics for every part.
+ */
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+#[cfg(feature = "count-allocations")]
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator::new();
+
+/// Allocations made while running one part.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    /// Number of allocations, including reallocations.
+    pub allocations: u64,
+    /// Total bytes requested by those allocations.
+    pub bytes: u64,
+    /// The highest number of bytes that were live at once, on top of what was live before.
+    pub peak: u64,
+}
+
+/// Wraps the system allocator and counts allocations.
+pub struct CountingAllocator {
+    allocations: AtomicU64,
+    bytes: AtomicU64,
+    live: AtomicU64,
+    peak: AtomicU64,
+}
+
+impl CountingAllocator {
+    pub const fn new() -> Self {
+        Self {
+            allocations: AtomicU64::new(0),
+            bytes: AtomicU64::new(0),
+            live: AtomicU64::new(0),
+            peak: AtomicU64::new(0),
+        }
+    }
+
+    fn record_alloc(&self, size: u64) {
+        self.allocations.fetch_add(1, Ordering::Relaxed);
+        self.bytes.fetch_add(size, Ordering::Relaxed);
+        let live = self.live.fetch_add(size, Ordering::Relaxed) + size;
+        self.peak.fetch_max(live, Ordering::Relaxed);
+    }
+
+    fn record_dealloc(&self, size: u64) {
+        self.live.fetch_sub(size, Ordering::Relaxed);
+    }
+
+    /// Runs `f` and returns the allocations it made.
+    pub fn measure<T>(&self, f: impl FnOnce() -> T) -> (T, AllocStats) {
+        let allocations = self.allocations.load(Ordering::Relaxed);
+        let bytes = self.bytes.load(Ordering::Relaxed);
+        let live = self.live.load(Ordering::Relaxed);
+        self.peak.store(live, Ordering::Relaxed);
+
+        let result = f();
+
+        let stats = AllocStats {
+            allocations: self.allocations.load(Ordering::Relaxed) - allocations,
+            bytes: self.bytes.load(Ordering::Relaxed) - bytes,
+            peak: self.peak.load(Ordering::Relaxed).saturating_sub(live),
+        };
+        (result, stats)
+    }
+}
+
+impl Default for CountingAllocator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            self.record_alloc(layout.size() as u64);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            self.record_alloc(layout.size() as u64);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        self.record_dealloc(layout.size() as u64);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            self.record_dealloc(layout.size() as u64);
+            self.record_alloc(new_size as u64);
+        }
+        new_ptr
+    }
+}
+
+/// Runs `f` and returns its allocations, or `None` if counting is not enabled.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    #[cfg(feature = "count-allocations")]
+    {
+        let (result, stats) = ALLOCATOR.measure(f);
+        (result, Some(stats))
+    }
+    #[cfg(not(feature = "count-allocations"))]
+    {
+        (f(), None)
+    }
+}
+
+pub fn format_bytes(bytes: u64) -> String {
+    match bytes {
+        b if b >= 1 << 20 => format!("{:.2} MiB", b as f64 / (1 << 20) as f64),
+        b if b >= 1 << 10 => format!("{:.2} KiB", b as f64 / (1 << 10) as f64),
+        b => format!("{} B", b),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_measure() {
+        let allocator = CountingAllocator::new();
+        let layout = Layout::from_size_align(64, 8).unwrap();
+
+        let (_, stats) = allocator.measure(|| unsafe {
+            let a = allocator.alloc(layout);
+            let b = allocator.alloc(layout);
+            allocator.dealloc(a, layout);
+            let b = allocator.realloc(b, layout, 256);
+            allocator.dealloc(b, Layout::from_size_align(256, 8).unwrap());
+        });
+
+        assert_eq!(
+            stats,
+            AllocStats {
+                allocations: 3,
+                bytes: 384,
+                peak: 256,
+            }
+        );
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(800), "800 B");
+        assert_eq!(format_bytes(1536), "1.50 KiB");
+        assert_eq!(format_bytes(3 << 20), "3.00 MiB");
+    }
+}
@@ -0,0 +1,175 @@
+/*
+ * Known answers per day, stored in `src/answers/YYYY/DD.txt`:
+ *
+ *     part1: 24000
+ *     part2: 45000
+ *
+ * Parts without a line are unknown. Newlines in answers are written as `\n`.
+ */
+use crate::{data_file, escape_record, src_dir, style, unescape_record, PartResult, YEAR};
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong { expected: String },
+    Unknown,
+}
+
+impl Verdict {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::Wrong { .. } => "wrong",
+            Verdict::Unknown => "unknown",
+        }
+    }
+}
+
+pub fn print_verdict(verdict: &Verdict) {
+    match verdict {
+        Verdict::Correct => println!("{}✔ correct{}", style::italic(), style::reset()),
+        Verdict::Wrong { expected } => println!(
+            "{}✘ wrong, expected {}{}",
+            style::italic(),
+            expected,
+            style::reset()
+        ),
+        Verdict::Unknown => println!("{}? unknown{}", style::italic(), style::reset()),
+    }
+}
+
+fn answers_path(day: u8) -> PathBuf {
+    src_dir().join(data_file("answers", YEAR, day))
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    parts: [Option<String>; 2],
+}
+
+impl Answers {
+    pub fn parse(contents: &str) -> Self {
+        let mut answers = Self::default();
+        for line in contents.lines() {
+            match line.split_once(':') {
+                Some(("part1", answer)) => answers.set(1, &unescape_record(answer.trim())),
+                Some(("part2", answer)) => answers.set(2, &unescape_record(answer.trim())),
+                _ => (),
+            }
+        }
+        answers
+    }
+
+    /// Loads the known answers of `day`. A missing file means that no answers are known.
+    pub fn load(day: u8) -> io::Result<Self> {
+        match fs::read_to_string(answers_path(day)) {
+            Ok(contents) => Ok(Self::parse(&contents)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn save(&self, day: u8) -> io::Result<()> {
+        let path = answers_path(day);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, self.to_string())
+    }
+
+    pub fn get(&self, part: u8) -> Option<&str> {
+        self.parts
+            .get(usize::from(part).checked_sub(1)?)?
+            .as_deref()
+    }
+
+    pub fn set(&mut self, part: u8, answer: &str) {
+        if let Some(slot) = usize::from(part)
+            .checked_sub(1)
+            .and_then(|i| self.parts.get_mut(i))
+        {
+            *slot = Some(answer.to_string());
+        }
+    }
+
+    /// Checks `result` against the known answer of its part. An unsolved part with a known answer is wrong.
+    pub fn verify(&self, result: &PartResult) -> Verdict {
+        match (self.get(result.part), &result.answer) {
+            (None, _) => Verdict::Unknown,
+            (Some(expected), Some(answer)) if expected == answer => Verdict::Correct,
+            (Some(expected), _) => Verdict::Wrong {
+                expected: expected.to_string(),
+            },
+        }
+    }
+}
+
+impl std::fmt::Display for Answers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, answer) in self.parts.iter().enumerate() {
+            if let Some(answer) = answer {
+                writeln!(f, "part{}: {}", i + 1, escape_record(answer))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn result(part: u8, answer: Option<&str>) -> PartResult {
+        PartResult {
+            part,
+            answer: answer.map(String::from),
+            elapsed: Duration::ZERO,
+            allocs: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_and_format() {
+        let answers = Answers::parse("part1: 24000\npart2: ##..\\n..##\n");
+        assert_eq!(answers.get(1), Some("24000"));
+        assert_eq!(answers.get(2), Some("##..\n..##"));
+        assert_eq!(answers.to_string(), "part1: 24000\npart2: ##..\\n..##\n");
+
+        let answers = Answers::parse("part2: MCD\n");
+        assert_eq!(answers.get(1), None);
+        assert_eq!(answers.to_string(), "part2: MCD\n");
+    }
+
+    #[test]
+    fn test_get_and_set_ignore_other_parts() {
+        let mut answers = Answers::default();
+        answers.set(0, "CMZ");
+        answers.set(3, "CMZ");
+        assert_eq!(answers, Answers::default());
+        assert_eq!(answers.get(0), None);
+        assert_eq!(answers.get(3), None);
+    }
+
+    #[test]
+    fn test_verify() {
+        let answers = Answers::parse("part1: CMZ\n");
+        assert_eq!(answers.verify(&result(1, Some("CMZ"))), Verdict::Correct);
+        assert_eq!(
+            answers.verify(&result(1, Some("MCD"))),
+            Verdict::Wrong {
+                expected: "CMZ".into()
+            }
+        );
+        assert_eq!(
+            answers.verify(&result(1, None)),
+            Verdict::Wrong {
+                expected: "CMZ".into()
+            }
+        );
+        assert_eq!(answers.verify(&result(2, Some("MCD"))), Verdict::Unknown);
+    }
+}
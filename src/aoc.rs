@@ -0,0 +1,93 @@
+/*
+ * A thin wrapper around aoc-cli (https://github.com/scarvalhojr/aoc-cli), which handles
+ * the session cookie for `cargo submit`. `cargo download` only falls back to it when
+ * there is no session cookie for `client`.
+ */
+use std::ffi::OsString;
+use std::io;
+use std::path::Path;
+use std::process::{Command, Output};
+
+/// The aoc-cli executable, looked up on `PATH`.
+pub const AOC_CLI: &str = "aoc";
+
+pub const INSTALL_HINT: &str =
+    "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.";
+
+/// Calls aoc-cli, see `AOC_CLI`.
+#[derive(Debug, Default)]
+pub struct AocCli {
+    /// Replaces `PATH` for looking up and running aoc-cli. Unset, the `PATH` of this process is used.
+    path: Option<OsString>,
+}
+
+impl AocCli {
+    fn command(&self) -> Command {
+        let mut cmd = Command::new(AOC_CLI);
+        if let Some(path) = &self.path {
+            cmd.env("PATH", path);
+        }
+        cmd
+    }
+
+    fn day_command(&self, year: u16, day: u8) -> Command {
+        let mut cmd = self.command();
+        cmd.args(["--year", &year.to_string(), "--day", &day.to_string()]);
+        cmd
+    }
+
+    /// Whether aoc-cli is installed and callable.
+    pub fn is_installed(&self) -> bool {
+        self.command().arg("-V").output().is_ok()
+    }
+
+    /// Downloads the input of `day` to `path`, which must not exist yet.
+    pub fn download(&self, year: u16, day: u8, path: &Path) -> io::Result<Output> {
+        self.day_command(year, day)
+            .arg("--input-file")
+            .arg(path)
+            .arg("download")
+            .output()
+    }
+
+    /// Submits `answer` for `part` of `day`. The verdict is in the output, see `submit::Outcome`.
+    pub fn submit(&self, year: u16, day: u8, part: u8, answer: &str) -> io::Result<Output> {
+        self.day_command(year, day)
+            .args(["submit", &part.to_string(), answer])
+            .output()
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use std::os::unix::fs::PermissionsExt;
+    use std::{env, fs, iter};
+
+    #[test]
+    fn test_submit_with_stub_on_path() {
+        let dir = env::temp_dir().join(format!("aoc-stub-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let stub = dir.join(AOC_CLI);
+        fs::write(&stub, "#!/bin/sh\necho \"$@\"\n").unwrap();
+        fs::set_permissions(&stub, fs::Permissions::from_mode(0o755)).unwrap();
+
+        let inherited = env::var_os("PATH").unwrap_or_default();
+        let path = env::join_paths(iter::once(dir.clone()).chain(env::split_paths(&inherited)));
+        let cli = AocCli {
+            path: Some(path.unwrap()),
+        };
+        assert!(cli.is_installed());
+        let output = cli.submit(2022, 5, 1, "CMZ").unwrap();
+        assert_eq!(
+            String::from_utf8_lossy(&output.stdout),
+            "--year 2022 --day 5 submit 1 CMZ\n"
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+        let missing = AocCli {
+            path: Some(dir.into_os_string()),
+        };
+        assert!(!missing.is_installed());
+    }
+}
@@ -0,0 +1,231 @@
+/*
+ * Statistical benchmarking of solutions (`--bench`).
+ * The first "cold" run is reported separately, followed by a few warm-up runs
+ * and the measured steady-state runs.
+ */
+use crate::alloc::AllocStats;
+use crate::days::{Parsed, Solution};
+use crate::{style, ParseResult, PartResult};
+use std::time::{Duration, Instant};
+
+const WARMUP_RUNS: u32 = 3;
+const DEFAULT_BUDGET: Duration = Duration::from_secs(1);
+
+/// How long to benchmark each part for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Config {
+    /// Measure exactly this many runs. Overrides `budget`.
+    pub runs: Option<u32>,
+    /// Measure as many runs as fit into this time (at least one).
+    pub budget: Duration,
+}
+
+impl Config {
+    /// Reads `--bench`, `--runs <n>` and `--budget <ms>`. Returns `None` without `--bench`.
+    pub fn from_args(args: &mut pico_args::Arguments) -> Result<Option<Self>, pico_args::Error> {
+        let bench = args.contains("--bench");
+        let runs = args.opt_value_from_str("--runs")?;
+        let budget = args
+            .opt_value_from_str("--budget")?
+            .map_or(DEFAULT_BUDGET, Duration::from_millis);
+
+        Ok(if bench {
+            Some(Self { runs, budget })
+        } else {
+            None
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    /// `0` for the parse step of a two-phase day.
+    pub part: u8,
+    /// The number of measured runs, excluding the cold and warm-up runs.
+    pub runs: usize,
+    pub cold: Duration,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// Computes statistics over `samples`. Panics if `samples` is empty.
+    pub fn from_samples(part: u8, cold: Duration, samples: &mut [Duration]) -> Self {
+        samples.sort_unstable();
+
+        let runs = samples.len();
+        let median = if runs.is_multiple_of(2) {
+            (samples[runs / 2 - 1] + samples[runs / 2]) / 2
+        } else {
+            samples[runs / 2]
+        };
+
+        let nanos = samples.iter().map(|s| s.as_nanos() as f64);
+        let mean = nanos.clone().sum::<f64>() / runs as f64;
+        let variance = nanos.map(|s| (s - mean).powi(2)).sum::<f64>() / runs as f64;
+
+        Self {
+            part,
+            runs,
+            cold,
+            min: samples[0],
+            median,
+            mean: Duration::from_nanos(mean.round() as u64),
+            stddev: Duration::from_nanos(variance.sqrt().round() as u64),
+        }
+    }
+}
+
+/// Warms `run` up and then collects the elapsed times it returns until `config` is satisfied.
+fn sample(run: impl Fn() -> Duration, config: &Config) -> Vec<Duration> {
+    for _ in 0..WARMUP_RUNS {
+        run();
+    }
+
+    let mut samples = vec![];
+    let timer = Instant::now();
+    loop {
+        samples.push(run());
+        let done = match config.runs {
+            Some(runs) => samples.len() >= runs as usize,
+            None => timer.elapsed() >= config.budget,
+        };
+        if done {
+            break;
+        }
+    }
+    samples
+}
+
+/// Benchmarks one part. The returned result is the cold run with its time replaced by the median.
+pub fn bench_part<I: ?Sized>(
+    run: impl Fn(&I) -> PartResult,
+    input: &I,
+    config: &Config,
+) -> (PartResult, Stats) {
+    let mut result = run(input);
+    let cold = result.elapsed;
+
+    let mut samples = sample(|| run(input).elapsed, config);
+
+    let stats = Stats::from_samples(result.part, cold, &mut samples);
+    result.elapsed = stats.median;
+    (result, stats)
+}
+
+/// Benchmarks the parser of a two-phase day, like `bench_part`. Other days get the raw input.
+pub fn bench_parse<'a>(
+    solution: &Solution,
+    input: &'a str,
+    config: &Config,
+) -> (Parsed<'a>, Option<(ParseResult, Stats)>) {
+    let (parsed, result) = solution.parse(input);
+    let Some(mut result) = result else {
+        return (parsed, None);
+    };
+
+    let mut samples = sample(
+        || {
+            solution
+                .parse(input)
+                .1
+                .map_or(Duration::ZERO, |r| r.elapsed)
+        },
+        config,
+    );
+
+    let stats = Stats::from_samples(0, result.elapsed, &mut samples);
+    result.elapsed = stats.median;
+    (parsed, Some((result, stats)))
+}
+
+fn print_stats(stats: &Stats, allocs: &Option<AllocStats>) {
+    println!(
+        "{}cold: {:.2?} | min: {:.2?} | median: {:.2?} | mean: {:.2?} ± {:.2?} ({} runs){}{}",
+        style::italic(),
+        stats.cold,
+        stats.min,
+        stats.median,
+        stats.mean,
+        stats.stddev,
+        stats.runs,
+        crate::format_allocs(allocs),
+        style::reset()
+    );
+}
+
+pub fn print_result(result: &PartResult, stats: &Stats) {
+    println!(
+        "🎄 {}Part {}{} 🎄",
+        style::bold(),
+        result.part,
+        style::reset()
+    );
+    match &result.answer {
+        Some(answer) => println!("{}", answer),
+        None => println!("not solved."),
+    }
+    print_stats(stats, &result.allocs);
+}
+
+pub fn print_parse(result: &ParseResult, stats: &Stats) {
+    println!("🎄 {}Parse{} 🎄", style::bold(), style::reset());
+    print_stats(stats, &result.allocs);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats_from_samples() {
+        let mut samples = [4, 1, 3, 2].map(Duration::from_micros);
+        let stats = Stats::from_samples(1, Duration::from_micros(10), &mut samples);
+        assert_eq!(stats.runs, 4);
+        assert_eq!(stats.cold, Duration::from_micros(10));
+        assert_eq!(stats.min, Duration::from_micros(1));
+        assert_eq!(stats.median, Duration::from_nanos(2500));
+        assert_eq!(stats.mean, Duration::from_nanos(2500));
+        assert_eq!(stats.stddev.as_nanos(), 1118);
+
+        let mut samples = [5, 1, 3].map(Duration::from_micros);
+        let stats = Stats::from_samples(2, Duration::ZERO, &mut samples);
+        assert_eq!(stats.median, Duration::from_micros(3));
+    }
+
+    #[test]
+    fn test_bench_part_runs() {
+        let config = Config {
+            runs: Some(5),
+            budget: DEFAULT_BUDGET,
+        };
+        let (result, stats) = bench_part(
+            |input| crate::run_part(1, |i: &str| Some(i.len()), input),
+            "abc",
+            &config,
+        );
+        assert_eq!(result.answer, Some("3".into()));
+        assert_eq!(stats.runs, 5);
+        assert_eq!(result.elapsed, stats.median);
+    }
+
+    #[test]
+    fn test_bench_parse_runs() {
+        let config = Config {
+            runs: Some(5),
+            budget: DEFAULT_BUDGET,
+        };
+        let solution = crate::days::get(5).unwrap();
+        let (_, parse) = bench_parse(solution, "", &config);
+        let (result, stats) = parse.unwrap();
+        assert_eq!(stats.part, 0);
+        assert_eq!(stats.runs, 5);
+        assert_eq!(result.elapsed, stats.median);
+
+        assert!(bench_parse(crate::days::get(1).unwrap(), "", &config)
+            .1
+            .is_none());
+    }
+}
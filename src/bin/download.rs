@@ -2,21 +2,65 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
+use advent_of_code::aoc::{self, AocCli};
+use advent_of_code::calendar;
+use advent_of_code::client::{self, Client};
+use advent_of_code::input_file::{self, Written};
+use advent_of_code::selection::{self, ALL_DAYS};
+use advent_of_code::style::{self, ColorChoice};
+use advent_of_code::{data_file, src_dir, YEAR};
 use std::io::Write;
-use std::path::PathBuf;
-use std::{env::temp_dir, io, process::Command};
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::Duration;
+use std::{env::temp_dir, io};
 use std::{fs, process};
 
+/// How long to wait between two requests of a bulk download, to go easy on the servers.
+const DEFAULT_DELAY: Duration = Duration::from_secs(2);
+
+enum Days {
+    One(u8),
+    /// Downloads the unlocked days of the range that have no input yet.
+    Bulk(RangeInclusive<u8>),
+}
+
 struct Args {
-    day: u8,
-    year: Option<i16>,
+    days: Days,
+    /// Defaults to `YEAR`, also decides which year folder the input is written to.
+    year: u16,
+    delay: Duration,
+    /// Replace an existing input that differs from the downloaded one.
+    force: bool,
 }
 
 fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
+    ColorChoice::from_args(&mut args)?;
+    let all = args.contains("--all");
+    let force = args.contains("--force");
+    let year = args.opt_value_from_str(["-y", "--year"])?.unwrap_or(YEAR);
+    let delay = args
+        .opt_value_from_str("--delay")?
+        .map_or(DEFAULT_DELAY, Duration::from_millis);
+    let days = if all {
+        Days::Bulk(ALL_DAYS)
+    } else {
+        args.free_from_fn(|s| {
+            let range = selection::parse_range(s)?;
+            Ok::<_, String>(if s.contains("..") {
+                Days::Bulk(range)
+            } else {
+                Days::One(*range.start())
+            })
+        })?
+    };
     Ok(Args {
-        day: args.free_from_str()?,
-        year: args.opt_value_from_str(["-y", "--year"])?,
+        days,
+        year,
+        delay,
+        force,
     })
 }
 
@@ -27,53 +71,56 @@ fn remove_file(path: &PathBuf) {
     }
 }
 
-fn exit_with_status(status: i32, path: &PathBuf) -> ! {
-    remove_file(path);
-    process::exit(status);
+/// Where inputs are downloaded from.
+enum Source {
+    Client(Client),
+    /// aoc-cli, for users without a session cookie where we can find it.
+    AocCli,
 }
 
-fn main() {
-    // acquire a temp file path to write aoc-cli output to.
-    // aoc-cli expects this file not to be present - delete just in case.
-    let mut tmp_file_path = temp_dir();
-    tmp_file_path.push("aoc_input_tmp");
-    remove_file(&tmp_file_path);
-
-    let args = match parse_args() {
-        Ok(args) => args,
-        Err(e) => {
-            eprintln!("Failed to process arguments: {}", e);
-            exit_with_status(1, &tmp_file_path);
+impl Source {
+    fn from_env() -> Result<Self, String> {
+        match Client::from_env() {
+            Ok(client) => Ok(Source::Client(client)),
+            // without a session of our own, aoc-cli may still have one in its config.
+            Err(client::Error::NoSession) if AocCli::default().is_installed() => Ok(Source::AocCli),
+            Err(client::Error::NoSession) => Err(format!(
+                "{}\nAlternatively, set {} or write your session cookie to ~/{} to download without it.",
+                aoc::INSTALL_HINT,
+                client::SESSION_ENV,
+                client::SESSION_FILE
+            )),
+            Err(e) => Err(e.to_string()),
         }
-    };
-
-    let day_padded = format!("{:02}", args.day);
-    let input_path = format!("src/inputs/{}.txt", day_padded);
-
-    // check if aoc binary exists and is callable.
-    if Command::new("aoc").arg("-V").output().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        exit_with_status(1, &tmp_file_path);
     }
 
-    let mut cmd_args = vec![];
-
-    if let Some(year) = args.year {
-        cmd_args.push("--year".into());
-        cmd_args.push(year.to_string());
+    fn fetch(&self, year: u16, day: u8) -> Result<String, String> {
+        match self {
+            Source::Client(client) => {
+                println!("Downloading input for day {}, {}...", day, year);
+                client
+                    .input(year, day)
+                    .map_err(|e| format!("could not download input: {}", e))
+            }
+            Source::AocCli => download_with_aoc(year, day),
+        }
     }
+}
 
-    cmd_args.append(&mut vec![
-        "--input-file".into(),
-        tmp_file_path.to_string_lossy().to_string(),
-        "--day".into(),
-        args.day.to_string(),
-        "download".into(),
-    ]);
+fn download_with_aoc(year: u16, day: u8) -> Result<String, String> {
+    // acquire a temp file path to write aoc-cli output to, unique to this download so that
+    // parallel downloads do not overwrite each other's input.
+    // aoc-cli expects this file not to be present - delete just in case.
+    let mut tmp_file_path = temp_dir();
+    tmp_file_path.push(format!("aoc_input_{}_{}_{}", process::id(), year, day));
+    remove_file(&tmp_file_path);
 
-    println!("Downloading input with >aoc {}", cmd_args.join(" "));
+    println!(
+        "Downloading input for day {}, {} with aoc-cli...",
+        day, year
+    );
 
-    match Command::new("aoc").args(cmd_args).output() {
+    let result = match AocCli::default().download(year, day, &tmp_file_path) {
         Ok(cmd_output) => {
             io::stdout()
                 .write_all(&cmd_output.stdout)
@@ -81,25 +128,168 @@ fn main() {
             io::stderr()
                 .write_all(&cmd_output.stderr)
                 .expect("could not write cmd stderr to pipe.");
-            if !cmd_output.status.success() {
-                exit_with_status(1, &tmp_file_path);
+            if cmd_output.status.success() {
+                fs::read_to_string(&tmp_file_path)
+                    .map_err(|e| format!("could not read downloaded input: {}", e))
+            } else {
+                Err("aoc-cli failed".into())
+            }
+        }
+        Err(e) => Err(format!("failed to spawn aoc-cli: {}", e)),
+    };
+    remove_file(&tmp_file_path);
+    result
+}
+
+fn input_path(year: u16, day: u8) -> PathBuf {
+    src_dir().join(data_file("inputs", year, day))
+}
+
+fn is_downloaded(path: &Path) -> bool {
+    fs::metadata(path).is_ok_and(|m| m.len() > 0)
+}
+
+fn format_days(days: &[u8]) -> String {
+    days.iter()
+        .map(|day| day.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn source() -> Source {
+    Source::from_env().unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        process::exit(1);
+    })
+}
+
+fn download_day(year: u16, day: u8, force: bool) {
+    let now = calendar::now();
+    if !calendar::is_unlocked(year, day, now) {
+        eprintln!(
+            "Day {}, {} is not unlocked yet, it unlocks at midnight EST on December {}.",
+            day, year, day
+        );
+        process::exit(1);
+    }
+
+    let input_path = input_path(year, day);
+    let result = source()
+        .fetch(year, day)
+        .and_then(|input| input_file::write(&input_path, &input, force));
+
+    match result {
+        Ok(written) => {
+            println!("---");
+            match written {
+                Written::Created => println!(
+                    "🎄 {}Successfully wrote input{} to \"{}\".",
+                    style::bold(),
+                    style::reset(),
+                    input_path.display()
+                ),
+                Written::Unchanged => {
+                    println!("🎄 \"{}\" is already up to date.", input_path.display())
+                }
+                Written::Replaced(backup) => println!(
+                    "🎄 {}Successfully replaced input{} in \"{}\", the old input is in \"{}\".",
+                    style::bold(),
+                    style::reset(),
+                    input_path.display(),
+                    backup.display()
+                ),
             }
         }
         Err(e) => {
-            eprintln!("failed to spawn aoc-cli: {}", e);
-            exit_with_status(1, &tmp_file_path);
+            eprintln!("{}", e);
+            process::exit(1);
         }
     }
+}
 
-    match fs::copy(&tmp_file_path, &input_path) {
-        Ok(_) => {
-            println!("---");
-            println!("🎄 Successfully wrote input to \"{}\".", &input_path);
-            exit_with_status(0, &tmp_file_path);
+fn download_days(year: u16, range: RangeInclusive<u8>, delay: Duration) {
+    let now = calendar::now();
+    let (unlocked, locked): (Vec<u8>, Vec<u8>) =
+        range.partition(|day| calendar::is_unlocked(year, *day, now));
+    let (skipped, missing): (Vec<u8>, Vec<u8>) = unlocked
+        .into_iter()
+        .partition(|day| is_downloaded(&input_path(year, *day)));
+
+    let source = if missing.is_empty() {
+        None
+    } else {
+        Some(source())
+    };
+
+    let mut fetched = vec![];
+    let mut failed = vec![];
+    for (i, day) in missing.into_iter().enumerate() {
+        if i > 0 {
+            thread::sleep(delay);
+        }
+        let source = source.as_ref().expect("a source for missing inputs");
+        match source
+            .fetch(year, day)
+            .and_then(|input| input_file::write(&input_path(year, day), &input, false))
+        {
+            Ok(_) => fetched.push(day),
+            Err(e) => {
+                eprintln!("{}", e);
+                failed.push(day);
+            }
+        }
+    }
+
+    println!("---");
+    println!(
+        "🎄 {}Fetched {} input(s){}{}",
+        style::bold(),
+        fetched.len(),
+        style::reset(),
+        if fetched.is_empty() {
+            String::new()
+        } else {
+            format!(": {}", format_days(&fetched))
         }
+    );
+    if !skipped.is_empty() {
+        println!(
+            "Skipped {} already downloaded: {}",
+            skipped.len(),
+            format_days(&skipped)
+        );
+    }
+    if !locked.is_empty() {
+        println!(
+            "Skipped {} not unlocked yet: {}",
+            locked.len(),
+            format_days(&locked)
+        );
+    }
+    if !failed.is_empty() {
+        println!("Failed {}: {}", failed.len(), format_days(&failed));
+        process::exit(1);
+    }
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
         Err(e) => {
-            eprintln!("could not copy downloaded input to input file: {}", e);
-            exit_with_status(1, &tmp_file_path);
+            eprintln!("Failed to process arguments: {}", e);
+            eprintln!(
+                "Need to specify a day, a range of days or --all. example: `cargo download 7`"
+            );
+            process::exit(1);
+        }
+    };
+
+    match args.days {
+        Days::One(day) => download_day(args.year, day, args.force),
+        Days::Bulk(_) if args.force => {
+            eprintln!("--force only works when downloading a single day.");
+            process::exit(1);
         }
+        Days::Bulk(range) => download_days(args.year, range, args.delay),
     }
 }
@@ -0,0 +1,177 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::calendar;
+use advent_of_code::client::Client;
+use advent_of_code::leaderboard::{self, Leaderboard, REFRESH_INTERVAL};
+use advent_of_code::selection::ALL_DAYS;
+use advent_of_code::style::{self, ColorChoice};
+use advent_of_code::{target_dir, YEAR};
+use std::path::PathBuf;
+use std::{env, fs, process};
+
+/// The id of the leaderboard to show when none is passed, e.g. the team's.
+const LEADERBOARD_ENV: &str = "AOC_LEADERBOARD_ID";
+
+enum Source {
+    /// Fetched with the session of `cargo download`, cached in `target/`.
+    Id(u64),
+    File(PathBuf),
+}
+
+struct Args {
+    source: Source,
+    year: u16,
+    /// Show completion times for this day instead of the latest one.
+    day: Option<u8>,
+    /// Show completion times for every day that has a star.
+    all_days: bool,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    ColorChoice::from_args(&mut args)?;
+    let year = args.opt_value_from_str(["-y", "--year"])?.unwrap_or(YEAR);
+    let day = args.opt_value_from_str("--day")?;
+    let all_days = args.contains("--all-days");
+    let source = match args.opt_value_from_str("--file")? {
+        Some(path) => Source::File(path),
+        None => match args.opt_free_from_str()? {
+            Some(id) => Source::Id(id),
+            None => Source::Id(
+                env::var(LEADERBOARD_ENV)
+                    .map_err(|_| pico_args::Error::MissingArgument)?
+                    .parse()
+                    .map_err(|e| pico_args::Error::ArgumentParsingFailed {
+                        cause: format!("{}: {}", LEADERBOARD_ENV, e),
+                    })?,
+            ),
+        },
+    };
+    Ok(Args {
+        source,
+        year,
+        day,
+        all_days,
+    })
+}
+
+fn cache_path(year: u16, id: u64) -> PathBuf {
+    target_dir().join(format!("aoc-leaderboard-{}-{}.json", year, id))
+}
+
+/// Reads the cached leaderboard if it was fetched less than `REFRESH_INTERVAL` ago.
+fn read_cache(path: &PathBuf) -> Option<String> {
+    let age = fs::metadata(path).ok()?.modified().ok()?.elapsed().ok()?;
+    if age < REFRESH_INTERVAL {
+        println!(
+            "{}Using the leaderboard fetched {} min ago.{}",
+            style::italic(),
+            age.as_secs() / 60,
+            style::reset()
+        );
+        fs::read_to_string(path).ok()
+    } else {
+        None
+    }
+}
+
+fn fetch(year: u16, id: u64) -> Result<String, String> {
+    let path = cache_path(year, id);
+    if let Some(contents) = read_cache(&path) {
+        return Ok(contents);
+    }
+
+    let client = Client::from_env().map_err(|e| e.to_string())?;
+    let contents = client
+        .get(&leaderboard::path(year, id))
+        .map_err(|e| format!("could not fetch leaderboard: {}", e))?;
+    // the site answers with its login page when the session is not a member of the leaderboard.
+    if let Err(e) = Leaderboard::parse(&contents) {
+        return Err(format!(
+            "{}, is the session cookie valid and a member of leaderboard {}?",
+            e, id
+        ));
+    }
+    if let Some(dir) = path.parent() {
+        let _ = fs::create_dir_all(dir);
+    }
+    if let Err(e) = fs::write(&path, &contents) {
+        eprintln!("Failed to cache leaderboard: {}", e);
+    }
+    Ok(contents)
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            eprintln!(
+                "Need to specify a leaderboard id, set {} or pass --file <path>. example: `cargo leaderboard 123456`",
+                LEADERBOARD_ENV
+            );
+            process::exit(1);
+        }
+    };
+
+    let contents = match &args.source {
+        Source::Id(id) => fetch(args.year, *id),
+        Source::File(path) => fs::read_to_string(path)
+            .map_err(|e| format!("could not read \"{}\": {}", path.display(), e)),
+    };
+    let leaderboard = match contents.and_then(|contents| Leaderboard::parse(&contents)) {
+        Ok(leaderboard) => leaderboard,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
+
+    let unlocked = calendar::unlocked_days(leaderboard.year, calendar::now()).count() as u8;
+    println!(
+        "🎄 {}Leaderboard {}{} 🎄",
+        style::bold(),
+        leaderboard.year,
+        style::reset()
+    );
+    for line in leaderboard::render_board(&leaderboard, unlocked) {
+        println!("{}", line);
+    }
+
+    let days: Vec<u8> = if args.all_days {
+        ALL_DAYS
+            .filter(|day| {
+                leaderboard
+                    .members
+                    .iter()
+                    .any(|member| member.completions.contains_key(day))
+            })
+            .collect()
+    } else {
+        args.day
+            .or_else(|| leaderboard.latest_day())
+            .into_iter()
+            .collect()
+    };
+
+    for day in days {
+        println!();
+        println!(
+            "🎄 {}Day {}{} 🎄 {}(time since unlock){}",
+            style::bold(),
+            day,
+            style::reset(),
+            style::italic(),
+            style::reset()
+        );
+        let times = leaderboard::render_times(&leaderboard, day);
+        if times.is_empty() {
+            println!("No stars yet.");
+        }
+        for line in times {
+            println!("{}", line);
+        }
+    }
+}
@@ -0,0 +1,82 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::answers::Answers;
+use advent_of_code::days;
+use advent_of_code::history::{self, History};
+use advent_of_code::readme::{self, Row};
+use advent_of_code::selection::ALL_DAYS;
+use advent_of_code::style::{self, ColorChoice};
+use advent_of_code::{crate_dir, YEAR};
+use std::path::PathBuf;
+use std::{fs, process};
+
+struct Args {
+    history: PathBuf,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    ColorChoice::from_args(&mut args)?;
+    Ok(Args {
+        history: args
+            .opt_value_from_str("--history")?
+            .unwrap_or_else(history::default_path),
+    })
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let history = History::load(&args.history).unwrap_or_else(|e| {
+        eprintln!("Failed to read benchmark history: {}", e);
+        process::exit(1);
+    });
+
+    let mut rows = vec![];
+    for day in ALL_DAYS {
+        let answers = Answers::load(day).unwrap_or_else(|e| {
+            eprintln!("Failed to read known answers of day {}: {}", day, e);
+            process::exit(1);
+        });
+        let row = Row::new(day, &answers, &history);
+        if !row.is_empty() || days::get(day).is_some() {
+            rows.push(row);
+        }
+    }
+
+    let readme_path = crate_dir().join("README.md");
+    let contents = fs::read_to_string(&readme_path).unwrap_or_else(|e| {
+        eprintln!("Failed to read \"{}\": {}", readme_path.display(), e);
+        process::exit(1);
+    });
+    let updated = readme::replace_table(&contents, &readme::render_table(YEAR, &rows))
+        .unwrap_or_else(|e| {
+            eprintln!("Failed to update \"{}\": {}", readme_path.display(), e);
+            process::exit(1);
+        });
+
+    if updated == contents {
+        println!("🎄 \"{}\" is up to date.", readme_path.display());
+        return;
+    }
+    match fs::write(&readme_path, updated) {
+        Ok(_) => println!(
+            "🎄 {}Updated{} the stars table in \"{}\".",
+            style::bold(),
+            style::reset(),
+            readme_path.display()
+        ),
+        Err(e) => {
+            eprintln!("Failed to write \"{}\": {}", readme_path.display(), e);
+            process::exit(1);
+        }
+    }
+}
@@ -2,24 +2,28 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
+use advent_of_code::puzzle;
+use advent_of_code::style::{self, ColorChoice};
+use advent_of_code::template::{self, Vars};
+use advent_of_code::{crate_dir, data_file, src_dir, YEAR};
 use std::{
-    fs::{File, OpenOptions},
-    io::Write,
+    fs::{self, File, OpenOptions},
+    io::{self, Read, Write},
+    path::Path,
     process,
 };
 
-const MODULE_TEMPLATE: &str = r###"pub fn part_one(input: &str) -> Option<u32> {
-    None
-}
+/// User templates in the crate root that replace the built-in ones if they exist, see `template`
+/// for placeholders.
+const MODULE_TEMPLATE_PATH: &str = "templates/module.rs.tmpl";
+const BIN_TEMPLATE_PATH: &str = "templates/bin.rs.tmpl";
 
-pub fn part_two(input: &str) -> Option<u32> {
+const MODULE_TEMPLATE: &str = r###"pub fn part_one(input: &str) -> Option<{{answer_type}}> {
     None
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", DAY);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+pub fn part_two(input: &str) -> Option<{{answer_type}}> {
+    None
 }
 
 #[cfg(test)]
@@ -28,45 +32,260 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_one(&input), None);
+        let input = crate::read_file("examples", {{day}});
+        assert_eq!(part_one(&input), {{part_one_expected}});
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", DAY);
+        let input = crate::read_file("examples", {{day}});
         assert_eq!(part_two(&input), None);
     }
 }
 "###;
 
-fn parse_args() -> Result<u8, pico_args::Error> {
+const BIN_TEMPLATE: &str = r###"fn main() {
+    advent_of_code::solve_day({{day}});
+}
+"###;
+
+struct Args {
+    day: u8,
+    answer_type: Option<String>,
+    /// A saved puzzle page, or `-` for stdin.
+    description: Option<String>,
+    /// A saved puzzle page to take the example and the answer of part one from.
+    example_from: Option<String>,
+    /// Which code block of the page is the example, starting at 1.
+    example_block: usize,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
-    args.free_from_str()
+    ColorChoice::from_args(&mut args)?;
+    Ok(Args {
+        answer_type: args.opt_value_from_fn("--answer-type", template::parse_answer_type)?,
+        description: args.opt_value_from_str("--with-description")?,
+        example_from: args.opt_value_from_str("--example-from")?,
+        example_block: args
+            .opt_value_from_fn("--example-block", |s| match s.parse() {
+                Ok(0) | Err(_) => Err("the example block has to be a number starting at 1"),
+                Ok(block) => Ok(block),
+            })?
+            .unwrap_or(1),
+        day: args.free_from_str()?,
+    })
+}
+
+/// Renders the template at `path`, or `default` if there is no such file.
+fn render_template(path: &str, default: &str, vars: &Vars) -> Result<String, String> {
+    let path = crate_dir().join(path);
+    let template = match fs::read_to_string(&path) {
+        Ok(template) => template,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => default.to_string(),
+        Err(e) => return Err(format!("could not read \"{}\": {}", path.display(), e)),
+    };
+    template::render(&template, vars).map_err(|e| format!("\"{}\": {}", path.display(), e))
+}
+
+/// Reads a saved puzzle page from a file, or from stdin for `-`.
+fn read_page(source: &str) -> Result<String, String> {
+    let mut page = String::new();
+    let read = if source == "-" {
+        io::stdin().read_to_string(&mut page).map(|_| ())
+    } else {
+        fs::read_to_string(source).map(|contents| page = contents)
+    };
+    read.map_err(|e| format!("could not read \"{}\": {}", source, e))?;
+
+    if page.trim().is_empty() {
+        return Err(format!("\"{}\" is empty", source));
+    }
+    Ok(page)
+}
+
+/// Converts a puzzle page into markdown. Text that is not a puzzle page, like the output of
+/// `aoc read`, is kept as it is.
+fn to_description(page: String) -> String {
+    puzzle::to_markdown(&page).unwrap_or(page)
+}
+
+/// Picks the example input and the answer to its part one out of a puzzle page.
+fn example(page: &str, block: usize) -> Result<(String, Option<String>), String> {
+    let blocks = puzzle::code_blocks(page);
+    let example = blocks.get(block - 1).cloned().ok_or_else(|| {
+        format!(
+            "the page has {} code block(s), there is no block {}",
+            blocks.len(),
+            block
+        )
+    })?;
+    Ok((example, puzzle::example_answer(page)))
 }
 
-fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
+fn write_description(path: &Path, description: &str) {
+    if let Some(dir) = path.parent() {
+        let _ = fs::create_dir_all(dir);
+    }
+    match fs::write(path, description) {
+        Ok(_) => {
+            println!("Created description file \"{}\"", path.display());
+        }
+        Err(e) => {
+            eprintln!("Failed to write description: {}", e);
+            process::exit(1);
+        }
+    }
+}
+
+fn safe_create_file(path: &Path) -> Result<File, std::io::Error> {
     OpenOptions::new().write(true).create_new(true).open(path)
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+/// Creates `path` and its year directory if they do not exist yet.
+fn create_file(path: &Path) -> Result<File, std::io::Error> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)
+}
+
+/// Adds `day` to the `days!` list of the registry, or returns `None` if there is no such list.
+fn register_day(registry: &str, day: u8) -> Option<String> {
+    let entry = format!("    {} => day{:02},\n", day, day);
+    if registry.contains(&entry) {
+        return Some(registry.to_string());
+    }
+
+    let list_start = registry.find("days! {\n")?;
+    let list_end = list_start + registry[list_start..].find("\n}")? + 1;
+
+    let mut registry = registry.to_string();
+    registry.insert_str(list_end, &entry);
+    Some(registry)
 }
 
 fn main() {
-    let day = match parse_args() {
-        Ok(day) => day,
+    let Args {
+        day,
+        answer_type,
+        description,
+        example_from,
+        example_block,
+    } = match parse_args() {
+        Ok(args) => args,
+        Err(pico_args::Error::Utf8ArgumentParsingFailed { cause, .. }) => {
+            eprintln!("Failed to process arguments: {}", cause);
+            process::exit(1);
+        }
         Err(_) => {
             eprintln!("Need to specify a day (as integer). example: `cargo scaffold 7`");
             process::exit(1);
         }
     };
 
+    // both options may name the same page, which can only be read once from stdin.
+    let mut pages: Vec<(String, String)> = vec![];
+    for source in description.iter().chain(example_from.iter()) {
+        if pages.iter().all(|(read, _)| read != source) {
+            match read_page(source) {
+                Ok(page) => pages.push((source.clone(), page)),
+                Err(e) => {
+                    eprintln!("Failed to read puzzle page: {}", e);
+                    process::exit(1);
+                }
+            }
+        }
+    }
+    let page = |source: &String| {
+        pages
+            .iter()
+            .find(|(read, _)| read == source)
+            .map(|(_, page)| page.clone())
+            .unwrap_or_default()
+    };
+
+    let description = description
+        .as_ref()
+        .map(|source| to_description(page(source)));
+    let (example, part_one_answer) = match example_from
+        .as_ref()
+        .map(|source| example(&page(source), example_block))
+    {
+        Some(Ok((example, answer))) => (Some(example), answer),
+        Some(Err(e)) => {
+            eprintln!("Failed to read example: {}", e);
+            process::exit(1);
+        }
+        None => (None, None),
+    };
+
+    let chosen_type = answer_type.is_some();
+    let mut answer_type = answer_type.unwrap_or_else(|| template::DEFAULT_ANSWER_TYPE.to_string());
+    let part_one_answer = part_one_answer.filter(|answer| {
+        match template::expected(&answer_type, answer) {
+            Ok(_) => true,
+            // without --answer-type, pick a type that fits the example answer.
+            Err(e) if !chosen_type => {
+                answer_type = if answer.parse::<i64>().is_ok() {
+                    "i64"
+                } else {
+                    "String"
+                }
+                .into();
+                println!("Using answer type {} ({})", answer_type, e);
+                true
+            }
+            Err(e) => {
+                eprintln!("Not pre-filling the answer of part one: {}", e);
+                false
+            }
+        }
+    });
+
+    // render both templates before creating any file, so that a broken template leaves nothing behind.
+    let vars = Vars {
+        day,
+        year: YEAR,
+        answer_type,
+        part_one_answer,
+    };
+    let (module, bin) = match render_template(MODULE_TEMPLATE_PATH, MODULE_TEMPLATE, &vars)
+        .and_then(|module| {
+            Ok((
+                module,
+                render_template(BIN_TEMPLATE_PATH, BIN_TEMPLATE, &vars)?,
+            ))
+        }) {
+        Ok(templates) => templates,
+        Err(e) => {
+            eprintln!("Failed to render template {}", e);
+            process::exit(1);
+        }
+    };
+
     let day_padded = format!("{:02}", day);
 
-    let input_path = format!("src/inputs/{}.txt", day_padded);
-    let example_path = format!("src/examples/{}.txt", day_padded);
-    let module_path = format!("src/bin/{}.rs", day_padded);
+    let src = src_dir();
+    let input_path = src.join(data_file("inputs", YEAR, day));
+    let example_path = src.join(data_file("examples", YEAR, day));
+    let module_path = src.join("days").join(format!("day{}.rs", day_padded));
+    let bin_path = src.join("bin").join(format!("{}.rs", day_padded));
+    let registry_path = src.join("days").join("mod.rs");
+    let description_path = src.join(data_file("puzzles", YEAR, day).with_extension("md"));
+
+    // the description of part two only becomes available once a day is solved halfway.
+    if let Some(description) = &description {
+        if module_path.exists() {
+            println!("Day {} already exists, updating its description.", day);
+            write_description(&description_path, description);
+            return;
+        }
+    }
 
     let mut file = match safe_create_file(&module_path) {
         Ok(file) => file,
@@ -76,9 +295,9 @@ fn main() {
         }
     };
 
-    match file.write_all(MODULE_TEMPLATE.replace("DAY", &day.to_string()).as_bytes()) {
+    match file.write_all(module.as_bytes()) {
         Ok(_) => {
-            println!("Created module file \"{}\"", &module_path);
+            println!("Created module file \"{}\"", module_path.display());
         }
         Err(e) => {
             eprintln!("Failed to write module contents: {}", e);
@@ -86,9 +305,48 @@ fn main() {
         }
     }
 
+    let mut file = match safe_create_file(&bin_path) {
+        Ok(file) => file,
+        Err(e) => {
+            eprintln!("Failed to create binary file: {}", e);
+            process::exit(1);
+        }
+    };
+
+    match file.write_all(bin.as_bytes()) {
+        Ok(_) => {
+            println!("Created binary file \"{}\"", bin_path.display());
+        }
+        Err(e) => {
+            eprintln!("Failed to write binary contents: {}", e);
+            process::exit(1);
+        }
+    }
+
+    let registry = fs::read_to_string(&registry_path)
+        .ok()
+        .and_then(|registry| register_day(&registry, day));
+
+    match registry.map(|registry| fs::write(&registry_path, registry)) {
+        Some(Ok(_)) => {
+            println!("Registered day {} in \"{}\"", day, registry_path.display());
+        }
+        Some(Err(e)) => {
+            eprintln!("Failed to register day: {}", e);
+            process::exit(1);
+        }
+        None => {
+            eprintln!(
+                "Failed to register day: no `days!` list in \"{}\"",
+                registry_path.display()
+            );
+            process::exit(1);
+        }
+    }
+
     match create_file(&input_path) {
         Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+            println!("Created empty input file \"{}\"", input_path.display());
         }
         Err(e) => {
             eprintln!("Failed to create input file: {}", e);
@@ -97,18 +355,38 @@ fn main() {
     }
 
     match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
-        }
+        Ok(file) => match example {
+            Some(example) if file.metadata().is_ok_and(|m| m.len() == 0) => {
+                match fs::write(&example_path, example) {
+                    Ok(_) => println!(
+                        "Created example file \"{}\" from code block {}",
+                        example_path.display(),
+                        example_block
+                    ),
+                    Err(e) => {
+                        eprintln!("Failed to write example: {}", e);
+                        process::exit(1);
+                    }
+                }
+            }
+            Some(_) => println!("Kept existing example file \"{}\"", example_path.display()),
+            None => println!("Created empty example file \"{}\"", example_path.display()),
+        },
         Err(e) => {
             eprintln!("Failed to create example file: {}", e);
             process::exit(1);
         }
     }
 
+    if let Some(description) = &description {
+        write_description(&description_path, description);
+    }
+
     println!("---");
     println!(
-        "🎄 Type `cargo solve {}` to run your solution.",
-        &day_padded
+        "🎄 Type {}`cargo solve {}`{} to run your solution.",
+        style::bold(),
+        &day_padded,
+        style::reset()
     );
 }
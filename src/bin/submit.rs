@@ -0,0 +1,162 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::answers::Answers;
+use advent_of_code::aoc::{self, AocCli};
+use advent_of_code::days;
+use advent_of_code::style::{self, ColorChoice};
+use advent_of_code::submit::{Outcome, Submission, Submissions};
+use advent_of_code::{print_result, try_read_file, YEAR};
+use std::io::{self, Write};
+use std::process;
+
+struct Args {
+    day: u8,
+    part: u8,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    ColorChoice::from_args(&mut args)?;
+    Ok(Args {
+        day: args.free_from_str()?,
+        part: args.free_from_fn(|s| match s {
+            "1" => Ok(1),
+            "2" => Ok(2),
+            _ => Err("part has to be 1 or 2"),
+        })?,
+    })
+}
+
+fn main() {
+    let Args { day, part } = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            eprintln!("Need to specify a day and a part. example: `cargo submit 7 1`");
+            process::exit(1);
+        }
+    };
+
+    let solution = match days::get(day) {
+        Some(solution) => solution,
+        None => {
+            eprintln!("Day {} is not registered in src/days/mod.rs.", day);
+            process::exit(1);
+        }
+    };
+    let input = match try_read_file("inputs", day) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("{} ({})", e.describe(day), e);
+            process::exit(1);
+        }
+    };
+
+    let (parsed, _) = solution.parse(&input);
+    let run = if part == 1 {
+        solution.part_one
+    } else {
+        solution.part_two
+    };
+    let result = run(&parsed);
+    print_result(&result);
+
+    let answer = match result.answer {
+        Some(answer) => answer,
+        None => {
+            eprintln!("Part {} is not solved, there is nothing to submit.", part);
+            process::exit(1);
+        }
+    };
+
+    let mut answers = Answers::load(day).unwrap_or_else(|e| {
+        eprintln!("Failed to read known answers: {}", e);
+        process::exit(1);
+    });
+    match answers.get(part) {
+        Some(known) if known == answer => {
+            println!("🎄 {} is already known to be correct.", answer);
+            return;
+        }
+        Some(known) => {
+            eprintln!(
+                "Not submitting {}: part {} was already solved with {}.",
+                answer, part, known
+            );
+            process::exit(1);
+        }
+        None => (),
+    }
+
+    let mut submissions = Submissions::load(day).unwrap_or_else(|e| {
+        eprintln!("Failed to read earlier submissions: {}", e);
+        process::exit(1);
+    });
+    if let Err(reason) = submissions.check(part, &answer) {
+        eprintln!("Not submitting {}: {}.", answer, reason);
+        process::exit(1);
+    }
+
+    let aoc_cli = AocCli::default();
+    if !aoc_cli.is_installed() {
+        eprintln!("{}", aoc::INSTALL_HINT);
+        process::exit(1);
+    }
+
+    println!("Submitting {} for day {}, part {}...", answer, day, part);
+    let output = match aoc_cli.submit(YEAR, day, part, &answer) {
+        Ok(output) => output,
+        Err(e) => {
+            eprintln!("failed to spawn aoc-cli: {}", e);
+            process::exit(1);
+        }
+    };
+    io::stdout()
+        .write_all(&output.stdout)
+        .expect("could not write cmd stdout to pipe.");
+    io::stderr()
+        .write_all(&output.stderr)
+        .expect("could not write cmd stderr to pipe.");
+
+    let outcome = match Outcome::from_response(&String::from_utf8_lossy(&output.stdout)) {
+        Some(outcome) if output.status.success() => outcome,
+        _ => {
+            eprintln!("Could not read a verdict from the response, nothing was recorded.");
+            process::exit(1);
+        }
+    };
+
+    let submission = Submission {
+        part,
+        answer: answer.clone(),
+        outcome,
+    };
+    if let Err(e) = submissions.append(day, submission) {
+        eprintln!("Failed to record submission: {}", e);
+    }
+
+    println!("---");
+    if outcome == Outcome::Correct {
+        answers.set(part, &answer);
+        match answers.save(day) {
+            Ok(_) => println!(
+                "🎄 {}Correct!{} Saved {} as the answer of part {}.",
+                style::bold(),
+                style::reset(),
+                answer,
+                part
+            ),
+            Err(e) => eprintln!("Correct, but failed to save the answer: {}", e),
+        }
+    } else {
+        println!(
+            "🎄 Submission was {}{}{}.",
+            style::bold(),
+            outcome.as_str().replace('_', " "),
+            style::reset()
+        );
+        process::exit(1);
+    }
+}
@@ -0,0 +1,125 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::style::{self, ColorChoice};
+use advent_of_code::watch::{self, Watcher};
+use advent_of_code::{PartResult, RECORD_ENV};
+use std::process::{self, Command, Stdio};
+use std::thread;
+use std::time::Duration;
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+struct Args {
+    day: u8,
+    release: bool,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    ColorChoice::from_args(&mut args)?;
+    Ok(Args {
+        release: args.contains("--release"),
+        day: args.free_from_str()?,
+    })
+}
+
+/// Runs the example tests of `day`, returns whether they passed.
+fn run_tests(day: u8) -> bool {
+    let status = Command::new("cargo")
+        .args(["test", "--quiet", "--lib"])
+        .arg(format!("days::day{:02}::", day))
+        .status();
+    match status {
+        Ok(status) => status.success(),
+        Err(e) => {
+            eprintln!("Failed to run cargo test: {}", e);
+            false
+        }
+    }
+}
+
+/// Runs `day` against its input, returns `None` if the day could not be run.
+fn run_day(day: u8, release: bool) -> Option<Vec<PartResult>> {
+    let mut cmd = Command::new("cargo");
+    cmd.args(["run", "--quiet", "--bin", &format!("{:02}", day)]);
+    if release {
+        cmd.arg("--release");
+    }
+    let output = cmd.env(RECORD_ENV, "1").stderr(Stdio::inherit()).output();
+
+    match output {
+        Ok(output) if output.status.success() => Some(
+            String::from_utf8_lossy(&output.stdout)
+                .lines()
+                .filter_map(PartResult::from_record)
+                .collect(),
+        ),
+        Ok(_) => None,
+        Err(e) => {
+            eprintln!("Failed to run cargo run: {}", e);
+            None
+        }
+    }
+}
+
+fn print_results(previous: &[PartResult], results: &[PartResult]) {
+    for result in results {
+        let before = previous.iter().find(|p| p.part == result.part);
+        println!(
+            "{}Part {}:{} {} {}(elapsed: {:.2?}){}",
+            style::bold(),
+            result.part,
+            style::reset(),
+            watch::diff_answer(before, result),
+            style::italic(),
+            result.elapsed,
+            style::reset()
+        );
+    }
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(_) => {
+            eprintln!("Need to specify a day (as integer). example: `cargo watch-day 7`");
+            process::exit(1);
+        }
+    };
+
+    let mut watcher = Watcher::new(watch::day_files(args.day));
+    let mut previous = vec![];
+
+    println!("🎄 Watching day {:02}, press Ctrl-C to stop.", args.day);
+    loop {
+        println!("---");
+        if !run_tests(args.day) {
+            println!(
+                "{}Example tests failed, not running the input.{}",
+                style::italic(),
+                style::reset()
+            );
+        } else if let Some(results) = run_day(args.day, args.release) {
+            print_results(&previous, &results);
+            previous = results;
+        }
+
+        let changed = loop {
+            thread::sleep(POLL_INTERVAL);
+            let changed = watcher.changed();
+            if !changed.is_empty() {
+                break changed;
+            }
+        };
+        for file in changed {
+            println!(
+                "{}Changed: {}{}",
+                style::italic(),
+                file.display(),
+                style::reset()
+            );
+        }
+    }
+}
@@ -0,0 +1,182 @@
+/*
+ * Builds the day binaries once for a cargo profile, used by `cargo all --profile <name>`.
+ * The runner then executes `target/<profile>/DD` directly instead of running days in-process.
+ */
+use std::collections::HashMap;
+use std::env;
+use std::ffi::OsString;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Profile(String);
+
+impl FromStr for Profile {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "" => Err("profile name can not be empty".into()),
+            // cargo calls the debug profile "dev".
+            "debug" => Ok(Self("dev".into())),
+            name => Ok(Self(name.into())),
+        }
+    }
+}
+
+impl Profile {
+    pub fn name(&self) -> &str {
+        &self.0
+    }
+
+    /// The directory in `target` that cargo writes the binaries of this profile to.
+    pub fn dir(&self) -> &str {
+        match self.name() {
+            "dev" | "test" => "debug",
+            "bench" => "release",
+            name => name,
+        }
+    }
+
+    pub fn is_optimized(&self) -> bool {
+        self.dir() != "debug"
+    }
+}
+
+/// The outcome of building the binaries of some days.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Build {
+    /// Built binaries by day.
+    executables: HashMap<u8, PathBuf>,
+    /// The compiler errors if the build failed.
+    errors: Option<String>,
+}
+
+/// Reads the day and path of a binary from a `compiler-artifact` message of cargo.
+fn parse_artifact(line: &str) -> Option<(u8, PathBuf)> {
+    let message: serde_json::Value = serde_json::from_str(line).ok()?;
+    if message.get("reason")?.as_str()? != "compiler-artifact" {
+        return None;
+    }
+    let day = message.get("target")?.get("name")?.as_str()?.parse().ok()?;
+    let executable = message.get("executable")?.as_str()?;
+    Some((day, executable.into()))
+}
+
+impl Build {
+    /// Runs a single `cargo build` for the binaries of `days`. Binaries that fail to build are
+    /// reported by `executable`, the returned error is only for failing to run cargo at all.
+    pub fn run(profile: &Profile, days: &[u8]) -> io::Result<Self> {
+        // without any `--bin`, cargo would build every binary.
+        if days.is_empty() {
+            return Ok(Self::default());
+        }
+        let cargo = env::var_os("CARGO").unwrap_or_else(|| OsString::from("cargo"));
+        let mut cmd = Command::new(cargo);
+        cmd.current_dir(crate::crate_dir()).args([
+            "build",
+            "--keep-going",
+            "--message-format",
+            "json-render-diagnostics",
+            "--profile",
+            profile.name(),
+        ]);
+        for day in days {
+            cmd.args(["--bin", &format!("{:02}", day)]);
+        }
+
+        let output = cmd.output()?;
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        Ok(Self {
+            executables: stdout.lines().filter_map(parse_artifact).collect(),
+            errors: if output.status.success() {
+                None
+            } else {
+                Some(compiler_errors(&stderr))
+            },
+        })
+    }
+
+    /// The binary of `day`, or why it could not be built.
+    pub fn executable(&self, day: u8) -> Result<&Path, String> {
+        match (self.executables.get(&day), &self.errors) {
+            (Some(path), _) => Ok(path),
+            (None, Some(errors)) => Err(errors.clone()),
+            (None, None) => Err(format!("cargo did not build a binary for day {}", day)),
+        }
+    }
+}
+
+/// Drops the progress lines that cargo prints before the first error.
+fn compiler_errors(stderr: &str) -> String {
+    let lines = stderr.lines().collect::<Vec<_>>();
+    let first_error = lines
+        .iter()
+        .position(|line| line.starts_with("error"))
+        .unwrap_or(0);
+    lines[first_error..].join("\n").trim().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_profile() {
+        let profile = |name: &str| name.parse::<Profile>().unwrap();
+        assert_eq!(profile("debug").name(), "dev");
+        assert_eq!(profile("debug").dir(), "debug");
+        assert_eq!(profile("release").dir(), "release");
+        assert_eq!(profile("bench").dir(), "release");
+        assert_eq!(profile("fast").dir(), "fast");
+        assert!(!profile("dev").is_optimized());
+        assert!(profile("fast").is_optimized());
+        assert!("".parse::<Profile>().is_err());
+    }
+
+    #[test]
+    fn test_parse_artifact() {
+        let line = r#"{"reason":"compiler-artifact","package_id":"x","target":{"kind":["bin"],"name":"05","src_path":"/src/bin/05.rs"},"executable":"/a \"b\"/target/release/05","fresh":true}"#;
+        assert_eq!(
+            parse_artifact(line),
+            Some((5, PathBuf::from("/a \"b\"/target/release/05")))
+        );
+
+        let lib = r#"{"reason":"compiler-artifact","target":{"kind":["lib"],"name":"advent_of_code"},"executable":null}"#;
+        assert_eq!(parse_artifact(lib), None);
+        assert_eq!(
+            parse_artifact(r#"{"reason":"build-finished","success":false}"#),
+            None
+        );
+
+        // keys that also appear nested or inside strings must not be mistaken for the fields.
+        let nested = r#"{"reason":"compiler-artifact","manifest_path":"/\"executable\":\"x\"","profile":{"name":"12"},"target":{"kind":["bin"],"name":"07"},"executable":"/t/07"}"#;
+        assert_eq!(parse_artifact(nested), Some((7, PathBuf::from("/t/07"))));
+    }
+
+    #[test]
+    fn test_executable() {
+        let build = Build {
+            executables: HashMap::from([(1, PathBuf::from("target/release/01"))]),
+            errors: Some("error[E0308]: mismatched types".into()),
+        };
+        assert_eq!(build.executable(1), Ok(Path::new("target/release/01")));
+        assert_eq!(
+            build.executable(2),
+            Err("error[E0308]: mismatched types".into())
+        );
+        assert!(Build::default().executable(2).is_err());
+    }
+
+    #[test]
+    fn test_compiler_errors() {
+        let stderr = "   Compiling advent_of_code v0.8.0\nerror: expected `;`\n --> src/days/day05.rs:3:5\n\n";
+        assert_eq!(
+            compiler_errors(stderr),
+            "error: expected `;`\n --> src/days/day05.rs:3:5"
+        );
+    }
+}
@@ -0,0 +1,66 @@
+/*
+ * When puzzles unlock: day N of a year unlocks on December N at midnight in the
+ * puzzle's timezone, US Eastern Standard Time (UTC-5).
+ */
+use crate::selection::ALL_DAYS;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const UTC_OFFSET_SECS: i64 = -5 * 3600;
+const SECS_PER_DAY: i64 = 24 * 3600;
+
+/// Days since 1970-01-01 of a date in the proleptic Gregorian calendar.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// The unix timestamp at which `day` of `year` unlocks.
+pub fn unlock_time(year: u16, day: u8) -> i64 {
+    days_from_civil(year as i64, 12, day as i64) * SECS_PER_DAY - UTC_OFFSET_SECS
+}
+
+/// The current unix timestamp.
+pub fn now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs() as i64)
+}
+
+pub fn is_unlocked(year: u16, day: u8, now: i64) -> bool {
+    unlock_time(year, day) <= now
+}
+
+/// The days of `year` that are unlocked at `now`.
+pub fn unlocked_days(year: u16, now: i64) -> impl Iterator<Item = u8> {
+    ALL_DAYS.filter(move |day| is_unlocked(year, *day, now))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unlock_time() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(2000, 3, 1), 11_017);
+        // 2022-12-01T05:00:00Z and 2015-12-25T05:00:00Z
+        assert_eq!(unlock_time(2022, 1), 1_669_870_800);
+        assert_eq!(unlock_time(2015, 25), 1_451_019_600);
+    }
+
+    #[test]
+    fn test_unlocked_days() {
+        let day_five = unlock_time(2022, 5);
+        assert_eq!(
+            unlocked_days(2022, day_five - 1).collect::<Vec<_>>(),
+            vec![1, 2, 3, 4]
+        );
+        assert_eq!(unlocked_days(2022, day_five).last(), Some(5));
+        assert_eq!(unlocked_days(2022, unlock_time(2021, 25)).count(), 0);
+        assert_eq!(unlocked_days(2021, day_five).count(), 25);
+    }
+}
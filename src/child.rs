@@ -0,0 +1,108 @@
+/*
+ * Runs a child process with a timeout, used by the all-days runner to isolate days.
+ */
+use std::io::{self, Read};
+use std::process::{Child, Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(5);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChildOutcome {
+    Exited {
+        success: bool,
+        stdout: String,
+        stderr: String,
+    },
+    /// The child was killed after running longer than the timeout.
+    TimedOut,
+}
+
+fn read_pipe(pipe: Option<impl Read + Send + 'static>) -> thread::JoinHandle<String> {
+    thread::spawn(move || {
+        let mut buf = vec![];
+        if let Some(mut pipe) = pipe {
+            #[allow(unused_must_use)]
+            {
+                pipe.read_to_end(&mut buf);
+            }
+        }
+        String::from_utf8_lossy(&buf).into_owned()
+    })
+}
+
+fn wait_with_timeout(child: &mut Child, timeout: Duration) -> io::Result<Option<bool>> {
+    let timer = Instant::now();
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return Ok(Some(status.success()));
+        }
+        if timer.elapsed() >= timeout {
+            child.kill()?;
+            child.wait()?;
+            return Ok(None);
+        }
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+/// Runs `cmd` and captures its output, killing it once it runs longer than `timeout`.
+pub fn run_with_timeout(mut cmd: Command, timeout: Duration) -> io::Result<ChildOutcome> {
+    let mut child = cmd
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    // drain the pipes while waiting, a child blocked on a full pipe would never exit.
+    let stdout = read_pipe(child.stdout.take());
+    let stderr = read_pipe(child.stderr.take());
+
+    let status = wait_with_timeout(&mut child, timeout)?;
+
+    let stdout = stdout.join().unwrap_or_default();
+    let stderr = stderr.join().unwrap_or_default();
+
+    Ok(match status {
+        Some(success) => ChildOutcome::Exited {
+            success,
+            stdout,
+            stderr,
+        },
+        None => ChildOutcome::TimedOut,
+    })
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    fn sh(script: &str) -> Command {
+        let mut cmd = Command::new("sh");
+        cmd.args(["-c", script]);
+        cmd
+    }
+
+    #[test]
+    fn test_captures_output() {
+        assert_eq!(
+            run_with_timeout(sh("echo out; echo err >&2; exit 3"), Duration::from_secs(5)).unwrap(),
+            ChildOutcome::Exited {
+                success: false,
+                stdout: "out\n".into(),
+                stderr: "err\n".into(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_kills_after_timeout() {
+        let timer = Instant::now();
+        assert_eq!(
+            run_with_timeout(sh("exec sleep 10"), Duration::from_millis(100)).unwrap(),
+            ChildOutcome::TimedOut
+        );
+        assert!(timer.elapsed() < Duration::from_secs(5));
+    }
+}
@@ -0,0 +1,200 @@
+/*
+ * A small HTTP client for adventofcode.com, so that `cargo download` works without aoc-cli.
+ * The session cookie is read from the same places as aoc-cli reads it from: the
+ * `ADVENT_OF_CODE_SESSION` environment variable or the file `~/.adventofcode.session`.
+ */
+use std::fmt::{self, Display};
+use std::path::PathBuf;
+use std::time::Duration;
+use std::{env, fs, io};
+
+pub const SESSION_ENV: &str = "ADVENT_OF_CODE_SESSION";
+pub const SESSION_FILE: &str = ".adventofcode.session";
+/// Overrides the site to talk to, e.g. a local stand-in server for testing.
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+/// Identifies the person running the tool to the site, as its automation guidelines ask,
+/// e.g. with an email address or the URL of your repository.
+pub const USER_AGENT_ENV: &str = "AOC_USER_AGENT";
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Debug)]
+pub enum Error {
+    /// There is no session cookie in the environment or the session file.
+    NoSession,
+    Io(io::Error),
+    /// The site answered with an error status.
+    Status(u16, String),
+    Transport(String),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::NoSession => write!(
+                f,
+                "no session cookie, set {} or write it to ~/{}",
+                SESSION_ENV, SESSION_FILE
+            ),
+            Error::Io(e) => write!(f, "could not read the session file: {}", e),
+            Error::Status(status, body) => {
+                write!(f, "the server responded with {}", status)?;
+                match body.lines().next() {
+                    Some(line) if !line.trim().is_empty() => write!(f, ": {}", line.trim()),
+                    _ => Ok(()),
+                }
+            }
+            Error::Transport(e) => write!(f, "request failed: {}", e),
+        }
+    }
+}
+
+fn session_file() -> Option<PathBuf> {
+    env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .map(|home| PathBuf::from(home).join(SESSION_FILE))
+}
+
+/// Reads the session cookie, see the module docs.
+pub fn load_session() -> Result<String, Error> {
+    if let Ok(session) = env::var(SESSION_ENV) {
+        if !session.trim().is_empty() {
+            return Ok(session.trim().to_string());
+        }
+    }
+    let path = session_file().ok_or(Error::NoSession)?;
+    match fs::read_to_string(path) {
+        Ok(session) if !session.trim().is_empty() => Ok(session.trim().to_string()),
+        Ok(_) => Err(Error::NoSession),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Err(Error::NoSession),
+        Err(e) => Err(Error::Io(e)),
+    }
+}
+
+/// The User-Agent without `USER_AGENT_ENV`: this crate and the `repository` of its `Cargo.toml`.
+fn default_user_agent() -> String {
+    let tool = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+    match env!("CARGO_PKG_REPOSITORY") {
+        "" => tool.to_string(),
+        repository => format!("{} ({})", tool, repository),
+    }
+}
+
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str, user_agent: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            agent: ureq::AgentBuilder::new()
+                .user_agent(user_agent)
+                .timeout(TIMEOUT)
+                .build(),
+        }
+    }
+
+    /// A client for the site in `AOC_BASE_URL`, or adventofcode.com, with the user's session
+    /// and the User-Agent in `AOC_USER_AGENT`.
+    pub fn from_env() -> Result<Self, Error> {
+        let base_url = env::var(BASE_URL_ENV).unwrap_or_else(|_| BASE_URL.to_string());
+        let user_agent = env::var(USER_AGENT_ENV).unwrap_or_else(|_| default_user_agent());
+        Ok(Self::new(&base_url, &load_session()?, &user_agent))
+    }
+
+    /// Fetches `path` of the site, e.g. `/2022/day/5/input`.
+    pub fn get(&self, path: &str) -> Result<String, Error> {
+        let response = self
+            .agent
+            .get(&format!("{}{}", self.base_url, path))
+            .set("Cookie", &format!("session={}", self.session))
+            .call();
+        match response {
+            Ok(response) => response
+                .into_string()
+                .map_err(|e| Error::Transport(e.to_string())),
+            Err(ureq::Error::Status(status, response)) => Err(Error::Status(
+                status,
+                response.into_string().unwrap_or_default(),
+            )),
+            Err(e) => Err(Error::Transport(e.to_string())),
+        }
+    }
+
+    /// Fetches the puzzle input of `day`.
+    pub fn input(&self, year: u16, day: u8) -> Result<String, Error> {
+        self.get(&format!("/{}/day/{}/input", year, day))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::thread::{self, JoinHandle};
+
+    /// Serves one response per request on a local port, each as `(status, body)`.
+    /// Returns the base url and a handle that yields the raw request heads.
+    fn serve(responses: Vec<(u16, &'static str)>) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let mut requests = vec![];
+            for (status, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request = String::new();
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line.trim().is_empty() {
+                        break;
+                    }
+                    request.push_str(&line);
+                }
+                requests.push(request);
+                write!(
+                    stream,
+                    "HTTP/1.1 {} X\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+            requests
+        });
+        (url, handle)
+    }
+
+    #[test]
+    fn test_input() {
+        let (url, server) = serve(vec![(200, "1\n2\n"), (404, "404 Not Found\n")]);
+        let client = Client::new(&url, "abc", "me@example.com");
+
+        assert_eq!(client.input(2022, 5).unwrap(), "1\n2\n");
+        let error = client.input(2022, 26).unwrap_err();
+        assert!(matches!(error, Error::Status(404, _)));
+        assert_eq!(
+            error.to_string(),
+            "the server responded with 404: 404 Not Found"
+        );
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("GET /2022/day/5/input "));
+        assert!(requests[0].contains("Cookie: session=abc"));
+        assert!(requests[0].contains("User-Agent: me@example.com"));
+        assert!(requests[1].starts_with("GET /2022/day/26/input "));
+    }
+
+    #[test]
+    fn test_default_user_agent() {
+        assert!(default_user_agent().starts_with("advent_of_code/"));
+    }
+}
@@ -0,0 +1,112 @@
+/*
+ * Registry of all solved days.
+ * `cargo scaffold` adds new days to the `days!` list below.
+ *
+ * A day either solves both parts from the raw input (`1 => day01,`), or parses the input once
+ * and passes the parsed value to both parts (`5 => day05(parse),`). Two-phase days report the
+ * parse time separately from the part times. Their parsed value must not borrow from the input.
+ */
+use crate::{ParseResult, PartResult};
+use std::any::Any;
+
+/// What the part functions of a day get: the raw input, or the value returned by its parser.
+pub enum Parsed<'a> {
+    Raw(&'a str),
+    Value(Box<dyn Any>),
+}
+
+impl<'a> Parsed<'a> {
+    fn raw(&self) -> &'a str {
+        match self {
+            Parsed::Raw(input) => input,
+            Parsed::Value(_) => panic!("expected the raw input, got a parsed value"),
+        }
+    }
+
+    /// The value returned by `parser`. Passing the parser ties the type of the value to the
+    /// parameter of the parts, so a part that takes another type than its parser returns
+    /// does not compile.
+    fn parsed_by<P: 'static>(&self, _parser: fn(&str) -> P) -> &P {
+        match self {
+            Parsed::Value(value) => value
+                .downcast_ref()
+                .expect("parsed value comes from another parser"),
+            Parsed::Raw(_) => panic!("expected a parsed value, got the raw input"),
+        }
+    }
+}
+
+/// Parses the input of a two-phase day into a type-erased value.
+pub type Parser = fn(&str) -> Box<dyn Any>;
+
+/// A registered day with type-erased part functions.
+pub struct Solution {
+    pub day: u8,
+    /// Only set for two-phase days.
+    pub parser: Option<Parser>,
+    pub part_one: fn(&Parsed) -> PartResult,
+    pub part_two: fn(&Parsed) -> PartResult,
+}
+
+impl Solution {
+    /// Runs the parser of a two-phase day and times it. Other days get the raw input.
+    pub fn parse<'a>(&self, input: &'a str) -> (Parsed<'a>, Option<ParseResult>) {
+        match self.parser {
+            Some(parser) => {
+                let (value, result) = crate::run_parse(parser, input);
+                (Parsed::Value(value), Some(result))
+            }
+            None => (Parsed::Raw(input), None),
+        }
+    }
+}
+
+macro_rules! solution {
+    ($day:literal, $module:ident) => {
+        Solution {
+            day: $day,
+            parser: None,
+            part_one: |input| crate::run_part(1, $module::part_one, input.raw()),
+            part_two: |input| crate::run_part(2, $module::part_two, input.raw()),
+        }
+    };
+    ($day:literal, $module:ident, $parser:ident) => {
+        Solution {
+            day: $day,
+            parser: Some(|input| Box::new($module::$parser(input))),
+            part_one: |input| {
+                crate::run_part(1, $module::part_one, input.parsed_by($module::$parser))
+            },
+            part_two: |input| {
+                crate::run_part(2, $module::part_two, input.parsed_by($module::$parser))
+            },
+        }
+    };
+}
+
+macro_rules! days {
+    ($($day:literal => $module:ident $(($parser:ident))?,)*) => {
+        $(pub mod $module;)*
+
+        pub const ALL: &[Solution] = &[$(solution!($day, $module $(, $parser)?),)*];
+    };
+}
+
+days! {
+    1 => day01,
+    2 => day02,
+    3 => day03,
+    4 => day04,
+    5 => day05(parse),
+    6 => day06,
+    7 => day07,
+    8 => day08,
+    9 => day09,
+    10 => day10,
+    11 => day11,
+    12 => day12(parse),
+}
+
+pub fn get(day: u8) -> Option<&'static Solution> {
+    ALL.iter().find(|s| s.day == day)
+}
@@ -70,7 +70,7 @@ impl Grid {
     }
 
     pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &u8>> + '_ {
-        (0..self.width).into_iter().map(|c| self.column(c))
+        (0..self.width).map(|c| self.column(c))
     }
 
     pub fn manhattan_neighbors(
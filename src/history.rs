@@ -0,0 +1,193 @@
+/*
+ * Benchmark history of the all-days runner.
+ * Every release run appends one JSON line per solved part to `default_path()`.
+ */
+use crate::style;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+pub const DEFAULT_THRESHOLD: f64 = 10.0;
+
+/// The history file in the crate's `target/` directory.
+pub fn default_path() -> PathBuf {
+    crate::target_dir().join("aoc-bench.jsonl")
+}
+
+/// The timing of one part in one run of the runner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Entry {
+    /// When the run started, in seconds since the unix epoch. Identifies the run.
+    pub run: u64,
+    pub day: u8,
+    pub part: u8,
+    pub elapsed: Duration,
+}
+
+impl Entry {
+    pub fn to_json(&self) -> String {
+        format!(
+            "{{\"run\":{},\"day\":{},\"part\":{},\"elapsed_ns\":{}}}",
+            self.run,
+            self.day,
+            self.part,
+            self.elapsed.as_nanos()
+        )
+    }
+
+    pub fn from_json(line: &str) -> Option<Self> {
+        let value: serde_json::Value = serde_json::from_str(line).ok()?;
+        let number = |key| value.get(key)?.as_u64();
+        Some(Self {
+            run: number("run")?,
+            day: number("day")?.try_into().ok()?,
+            part: number("part")?.try_into().ok()?,
+            elapsed: Duration::from_nanos(number("elapsed_ns")?),
+        })
+    }
+}
+
+pub fn current_run() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+#[derive(Debug, Default)]
+pub struct History {
+    entries: Vec<Entry>,
+}
+
+impl History {
+    pub fn new(entries: Vec<Entry>) -> Self {
+        Self { entries }
+    }
+
+    /// Loads the history at `path`. A missing file is an empty history, unreadable lines are skipped.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(contents) => Ok(Self::new(
+                contents.lines().filter_map(Entry::from_json).collect(),
+            )),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn append(path: &Path, entries: &[Entry]) -> io::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        for entry in entries {
+            writeln!(file, "{}", entry.to_json())?;
+        }
+        Ok(())
+    }
+
+    fn part_entries(&self, day: u8, part: u8) -> impl Iterator<Item = &Entry> + '_ {
+        self.entries
+            .iter()
+            .filter(move |e| e.day == day && e.part == part)
+    }
+
+    /// The elapsed time of the most recent run of a part.
+    pub fn previous(&self, day: u8, part: u8) -> Option<Duration> {
+        self.part_entries(day, part)
+            .max_by_key(|e| e.run)
+            .map(|e| e.elapsed)
+    }
+
+    /// The fastest elapsed time of a part across all runs.
+    pub fn best(&self, day: u8, part: u8) -> Option<Duration> {
+        self.part_entries(day, part).map(|e| e.elapsed).min()
+    }
+}
+
+/// The relative change from `before` to `now` in percent.
+pub fn change(now: Duration, before: Duration) -> f64 {
+    if before.is_zero() {
+        return 0.0;
+    }
+    (now.as_nanos() as f64 - before.as_nanos() as f64) / before.as_nanos() as f64 * 100.0
+}
+
+/// Prints how `elapsed` compares to the previous and the best run of a part.
+/// A slowdown of more than `threshold` percent vs. the previous run is flagged as a regression.
+pub fn print_comparison(history: &History, day: u8, part: u8, elapsed: Duration, threshold: f64) {
+    let (previous, best) = match (history.previous(day, part), history.best(day, part)) {
+        (Some(previous), Some(best)) => (previous, best),
+        _ => return,
+    };
+
+    let vs_previous = change(elapsed, previous);
+    println!(
+        "{}previous: {:.2?} ({:+.1}%) | best: {:.2?} ({:+.1}%){}{}",
+        style::italic(),
+        previous,
+        vs_previous,
+        best,
+        change(elapsed, best),
+        style::reset(),
+        if vs_previous > threshold {
+            " ⚠ regression"
+        } else {
+            ""
+        }
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(run: u64, day: u8, part: u8, micros: u64) -> Entry {
+        Entry {
+            run,
+            day,
+            part,
+            elapsed: Duration::from_micros(micros),
+        }
+    }
+
+    #[test]
+    fn test_entry_json_roundtrip() {
+        let e = entry(1670000000, 5, 2, 37);
+        assert_eq!(
+            e.to_json(),
+            r#"{"run":1670000000,"day":5,"part":2,"elapsed_ns":37000}"#
+        );
+        assert_eq!(Entry::from_json(&e.to_json()), Some(e));
+        assert_eq!(Entry::from_json(r#"{"run":1,"day":5}"#), None);
+        assert_eq!(Entry::from_json("not json"), None);
+        assert_eq!(
+            Entry::from_json(
+                r#"{"meta":{"run":9},"run":1670000000,"day":5,"part":2,"elapsed_ns":37000}"#
+            ),
+            Some(e)
+        );
+    }
+
+    #[test]
+    fn test_previous_and_best() {
+        let history = History::new(vec![
+            entry(1, 5, 1, 30),
+            entry(2, 5, 1, 20),
+            entry(3, 5, 1, 25),
+            entry(3, 5, 2, 99),
+            entry(3, 6, 1, 1),
+        ]);
+        assert_eq!(history.previous(5, 1), Some(Duration::from_micros(25)));
+        assert_eq!(history.best(5, 1), Some(Duration::from_micros(20)));
+        assert_eq!(history.previous(7, 1), None);
+    }
+
+    #[test]
+    fn test_change() {
+        let d = Duration::from_micros;
+        assert_eq!(change(d(110), d(100)), 10.0);
+        assert_eq!(change(d(50), d(100)), -50.0);
+        assert_eq!(change(d(50), Duration::ZERO), 0.0);
+    }
+}
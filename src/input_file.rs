@@ -0,0 +1,194 @@
+/*
+ * Checks downloaded inputs before they are written, and writes them without losing
+ * an existing input: an input that differs from the existing one only replaces it when
+ * forced, and the old one is kept as a backup, e.g. `src/inputs/2022/05.txt.bak`.
+ */
+use std::fmt::{self, Display};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process;
+
+/// Why a downloaded body is not a puzzle input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Invalid {
+    Empty,
+    LoggedOut,
+    Locked,
+    NotFound,
+    Html,
+}
+
+impl Display for Invalid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Invalid::Empty => "the input is empty",
+            Invalid::LoggedOut => "not logged in, the session cookie is missing or expired",
+            Invalid::Locked => "the puzzle is not unlocked yet",
+            Invalid::NotFound => "the puzzle was not found",
+            Invalid::Html => "got an HTML page instead of the input",
+        })
+    }
+}
+
+/// Checks that `body` looks like a puzzle input rather than one of the site's error pages.
+pub fn validate(body: &str) -> Result<(), Invalid> {
+    let lowercase = body.trim_start().to_lowercase();
+    if lowercase.is_empty() {
+        Err(Invalid::Empty)
+    } else if body.contains("Please log in") {
+        Err(Invalid::LoggedOut)
+    } else if body.contains("Please don't repeatedly request this endpoint before it unlocks") {
+        Err(Invalid::Locked)
+    } else if lowercase.starts_with("404 not found") {
+        Err(Invalid::NotFound)
+    } else if ["<!doctype", "<html", "<head", "<body"]
+        .iter()
+        .any(|tag| lowercase.contains(tag))
+    {
+        // inputs may contain `<` themselves, e.g. 2022 day 17, so only look for page markup.
+        Err(Invalid::Html)
+    } else {
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Written {
+    Created,
+    /// The existing input is the same as the downloaded one.
+    Unchanged,
+    /// The existing input was different and moved to the backup.
+    Replaced(PathBuf),
+}
+
+pub fn backup_path(path: &Path) -> PathBuf {
+    let mut backup = path.as_os_str().to_owned();
+    backup.push(".bak");
+    PathBuf::from(backup)
+}
+
+/// A temp path next to `path` that no other process uses.
+pub fn temp_path(path: &Path) -> PathBuf {
+    let mut temp = path.as_os_str().to_owned();
+    temp.push(format!(".{}.tmp", process::id()));
+    PathBuf::from(temp)
+}
+
+/// Validates `input` and writes it to `path`, see the module docs.
+pub fn write(path: &Path, input: &str, force: bool) -> Result<Written, String> {
+    validate(input).map_err(|e| format!("not writing \"{}\": {}", path.display(), e))?;
+
+    // compared as bytes, so that an existing input that is not UTF-8 is still kept.
+    let existing = match fs::read(path) {
+        Ok(existing) => existing,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => vec![],
+        Err(e) => {
+            return Err(format!(
+                "could not read the existing input \"{}\": {}",
+                path.display(),
+                e
+            ))
+        }
+    };
+    if existing == input.as_bytes() {
+        return Ok(Written::Unchanged);
+    }
+    let replace = !existing.is_empty();
+    if replace && !force {
+        return Err(format!(
+            "\"{}\" already contains a different input, use --force to replace it",
+            path.display()
+        ));
+    }
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| format!("could not create input directory: {}", e))?;
+    }
+    // write to a temp file first, so that a failed write does not leave a partial input behind.
+    let temp = temp_path(path);
+    fs::write(&temp, input).map_err(|e| format!("could not write input: {}", e))?;
+    if replace {
+        let backup = backup_path(path);
+        if let Err(e) = fs::copy(path, &backup) {
+            let _ = fs::remove_file(&temp);
+            return Err(format!("could not back up the existing input: {}", e));
+        }
+    }
+    if let Err(e) = fs::rename(&temp, path) {
+        let _ = fs::remove_file(&temp);
+        return Err(format!("could not write input: {}", e));
+    }
+
+    Ok(if replace {
+        Written::Replaced(backup_path(path))
+    } else {
+        Written::Created
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(validate("1\n2\n"), Ok(()));
+        assert_eq!(validate(">><<<>\n"), Ok(()));
+        assert_eq!(validate(" \n"), Err(Invalid::Empty));
+        assert_eq!(
+            validate("Puzzle inputs differ by user.  Please log in to get your puzzle input.\n"),
+            Err(Invalid::LoggedOut)
+        );
+        assert_eq!(
+            validate("Please don't repeatedly request this endpoint before it unlocks! The calendar countdown is synchronized with the server time; the link will be enabled on the calendar the instant this puzzle becomes available.\n"),
+            Err(Invalid::Locked)
+        );
+        assert_eq!(validate("404 Not Found\n"), Err(Invalid::NotFound));
+        assert_eq!(
+            validate("<!DOCTYPE html>\n<html lang=\"en-us\">"),
+            Err(Invalid::Html)
+        );
+    }
+
+    #[test]
+    fn test_write() {
+        let dir = std::env::temp_dir().join(format!("aoc-input-file-{}", process::id()));
+        let path = dir.join("2022").join("05.txt");
+
+        assert_eq!(write(&path, "1\n", false), Ok(Written::Created));
+        assert_eq!(write(&path, "1\n", false), Ok(Written::Unchanged));
+        assert!(write(&path, "2\n", false).is_err());
+        assert!(write(&path, "404 Not Found", true).is_err());
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1\n");
+
+        assert_eq!(
+            write(&path, "2\n", true),
+            Ok(Written::Replaced(dir.join("2022").join("05.txt.bak")))
+        );
+        assert_eq!(fs::read_to_string(&path).unwrap(), "2\n");
+        assert_eq!(fs::read_to_string(backup_path(&path)).unwrap(), "1\n");
+        assert!(!temp_path(&path).exists());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_write_keeps_non_utf8_input() {
+        let dir = std::env::temp_dir().join(format!("aoc-input-file-utf8-{}", process::id()));
+        let path = dir.join("05.txt");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(&path, [0xff, 0xfe, b'\n']).unwrap();
+
+        assert!(write(&path, "1\n", false).is_err());
+        assert_eq!(fs::read(&path).unwrap(), [0xff, 0xfe, b'\n']);
+
+        assert_eq!(
+            write(&path, "1\n", true),
+            Ok(Written::Replaced(backup_path(&path)))
+        );
+        assert_eq!(fs::read(backup_path(&path)).unwrap(), [0xff, 0xfe, b'\n']);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
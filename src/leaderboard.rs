@@ -0,0 +1,272 @@
+/*
+ * A private leaderboard, as served by `/{year}/leaderboard/private/view/{id}.json`:
+ *
+ *     {"event":"2022","members":{"123":{"id":123,"name":"Alice","local_score":42,"stars":3,
+ *      "completion_day_level":{"1":{"1":{"get_star_ts":1669871012},"2":{...}}}}}}
+ *
+ * The site asks to fetch it at most once every 15 minutes, see `REFRESH_INTERVAL`.
+ */
+use crate::{calendar, style};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+pub const REFRESH_INTERVAL: Duration = Duration::from_secs(15 * 60);
+
+pub fn path(year: u16, id: u64) -> String {
+    format!("/{}/leaderboard/private/view/{}.json", year, id)
+}
+
+/// Reads a number, also from a string, as some fields are numbers in strings, e.g. the `event`.
+fn as_i64(value: &Value) -> Option<i64> {
+    match value {
+        Value::String(s) => s.parse().ok(),
+        value => value.as_i64(),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Member {
+    pub id: u64,
+    /// `None` for anonymous users.
+    pub name: Option<String>,
+    pub local_score: i64,
+    pub stars: i64,
+    /// When each part of a day was completed, as unix timestamps.
+    pub completions: BTreeMap<u8, [Option<i64>; 2]>,
+}
+
+impl Member {
+    fn parse(value: &Value) -> Option<Self> {
+        let mut completions = BTreeMap::new();
+        for (day, parts) in value.get("completion_day_level")?.as_object()? {
+            let completed = |part: &str| as_i64(parts.get(part)?.get("get_star_ts")?);
+            completions.insert(day.parse().ok()?, [completed("1"), completed("2")]);
+        }
+        Some(Self {
+            id: as_i64(value.get("id")?)? as u64,
+            name: value.get("name").and_then(Value::as_str).map(String::from),
+            local_score: as_i64(value.get("local_score")?)?,
+            stars: as_i64(value.get("stars")?)?,
+            completions,
+        })
+    }
+
+    pub fn display_name(&self) -> String {
+        match &self.name {
+            Some(name) => name.clone(),
+            None => format!("(anonymous user #{})", self.id),
+        }
+    }
+
+    pub fn completed(&self, day: u8, part: u8) -> Option<i64> {
+        self.completions.get(&day)?[part as usize - 1]
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Leaderboard {
+    pub year: u16,
+    /// Sorted by local score, best first.
+    pub members: Vec<Member>,
+}
+
+impl Leaderboard {
+    pub fn parse(contents: &str) -> Result<Self, String> {
+        let value = serde_json::from_str::<Value>(contents)
+            .map_err(|e| format!("not a leaderboard: {}", e))?;
+        let year = value
+            .get("event")
+            .and_then(as_i64)
+            .and_then(|year| year.try_into().ok())
+            .ok_or("not a leaderboard: no event year")?;
+        let mut members = value
+            .get("members")
+            .and_then(Value::as_object)
+            .ok_or("not a leaderboard: no members")?
+            .iter()
+            .map(|(id, member)| {
+                Member::parse(member).ok_or_else(|| format!("could not read member {}", id))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        members.sort_by(|a, b| {
+            (b.local_score, b.stars)
+                .cmp(&(a.local_score, a.stars))
+                .then_with(|| a.display_name().cmp(&b.display_name()))
+        });
+        Ok(Self { year, members })
+    }
+
+    /// The latest day that any member got a star on.
+    pub fn latest_day(&self) -> Option<u8> {
+        self.members
+            .iter()
+            .flat_map(|member| member.completions.keys())
+            .max()
+            .copied()
+    }
+}
+
+/// Formats the time between the unlock of a puzzle and a star, e.g. `1h 02m 03s`.
+pub fn format_offset(secs: i64) -> String {
+    let (days, hours, minutes, secs) = (
+        secs / 86_400,
+        secs % 86_400 / 3600,
+        secs % 3600 / 60,
+        secs % 60,
+    );
+    if days > 0 {
+        format!("{}d {:02}h {:02}m", days, hours, minutes)
+    } else if hours > 0 {
+        format!("{}h {:02}m {:02}s", hours, minutes, secs)
+    } else {
+        format!("{}m {:02}s", minutes, secs)
+    }
+}
+
+/// The leaderboard as on the site: rank, local score and a star for each of the first `days`,
+/// `★` for both parts, `☆` for part one and `·` for none.
+pub fn render_board(leaderboard: &Leaderboard, days: u8) -> Vec<String> {
+    let indent = " ".repeat(11);
+    let tens: String = (1..=days)
+        .map(|day| match day / 10 {
+            0 => ' ',
+            tens => char::from(b'0' + tens),
+        })
+        .collect();
+    let ones: String = (1..=days).map(|day| char::from(b'0' + day % 10)).collect();
+    let mut lines = vec![format!("{}{}", indent, tens), format!("{}{}", indent, ones)];
+
+    for (rank, member) in leaderboard.members.iter().enumerate() {
+        let stars: String = (1..=days)
+            .map(
+                |day| match (member.completed(day, 1), member.completed(day, 2)) {
+                    (Some(_), Some(_)) => '★',
+                    (Some(_), None) | (None, Some(_)) => '☆',
+                    (None, None) => '·',
+                },
+            )
+            .collect();
+        lines.push(format!(
+            "{:>3}) {:>5} {} {}{}{}",
+            rank + 1,
+            member.local_score,
+            stars,
+            style::bold(),
+            member.display_name(),
+            style::reset()
+        ));
+    }
+    lines
+}
+
+/// How long each member took for `day` after it unlocked, fastest to both stars first.
+pub fn render_times(leaderboard: &Leaderboard, day: u8) -> Vec<String> {
+    let unlock = calendar::unlock_time(leaderboard.year, day);
+    let mut members: Vec<_> = leaderboard
+        .members
+        .iter()
+        .filter(|member| member.completions.contains_key(&day))
+        .collect();
+    members.sort_by_key(|member| {
+        (
+            member.completed(day, 2).unwrap_or(i64::MAX),
+            member.completed(day, 1).unwrap_or(i64::MAX),
+        )
+    });
+
+    let width = members
+        .iter()
+        .map(|member| member.display_name().chars().count())
+        .max()
+        .unwrap_or(0);
+    members
+        .iter()
+        .map(|member| {
+            let time = |part| {
+                member
+                    .completed(day, part)
+                    .map_or("-".into(), |ts| format_offset(ts - unlock))
+            };
+            format!(
+                "{:<width$} | part 1: {:>11} | part 2: {:>11}",
+                member.display_name(),
+                time(1),
+                time(2),
+                width = width
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // day 1 of 2022 unlocked at 1669870800.
+    const LEADERBOARD: &str = r#"{"event":"2022","owner_id":1,"members":{
+        "1":{"id":1,"name":"Alice","local_score":10,"stars":3,"last_star_ts":1669958000,
+             "completion_day_level":{"1":{"1":{"get_star_ts":1669871100,"star_index":1},
+                                         "2":{"get_star_ts":1669874523,"star_index":2}},
+                                     "2":{"1":{"get_star_ts":1669958000,"star_index":3}}}},
+        "2":{"id":2,"name":null,"local_score":4,"stars":1,"last_star_ts":1669900000,
+             "completion_day_level":{"1":{"1":{"get_star_ts":1669900000,"star_index":4}}}},
+        "3":{"id":3,"name":"Carol","local_score":0,"stars":0,"last_star_ts":0,
+             "completion_day_level":{}}}}"#;
+
+    #[test]
+    fn test_parse() {
+        let leaderboard = Leaderboard::parse(LEADERBOARD).unwrap();
+        assert_eq!(leaderboard.year, 2022);
+        let names: Vec<_> = leaderboard
+            .members
+            .iter()
+            .map(Member::display_name)
+            .collect();
+        assert_eq!(names, vec!["Alice", "(anonymous user #2)", "Carol"]);
+        assert_eq!(leaderboard.members[0].completed(1, 2), Some(1669874523));
+        assert_eq!(leaderboard.members[0].completed(2, 2), None);
+        assert_eq!(leaderboard.latest_day(), Some(2));
+
+        let numbered = Leaderboard::parse("{\"event\":2021,\"members\":{}}").unwrap();
+        assert_eq!(numbered.year, 2021);
+        assert!(Leaderboard::parse("<!DOCTYPE html>").is_err());
+        assert!(Leaderboard::parse("{\"members\":{}}").is_err());
+    }
+
+    #[test]
+    fn test_format_offset() {
+        assert_eq!(format_offset(65), "1m 05s");
+        assert_eq!(format_offset(3723), "1h 02m 03s");
+        assert_eq!(format_offset(90_000), "1d 01h 00m");
+    }
+
+    #[test]
+    fn test_render() {
+        let leaderboard = Leaderboard::parse(LEADERBOARD).unwrap();
+        assert_eq!(
+            render_board(&leaderboard, 11)[..2],
+            [
+                format!("{}         11", " ".repeat(11)),
+                format!("{}12345678901", " ".repeat(11))
+            ]
+        );
+
+        let board = render_board(&leaderboard, 2);
+        assert_eq!(board[0].trim(), "");
+        assert_eq!(board[1], format!("{}12", " ".repeat(11)));
+        assert!(board[2].starts_with("  1)    10 ★☆ "));
+        assert!(board[2].ends_with("Alice"));
+        assert!(board[3].starts_with("  2)     4 ☆· "));
+        assert!(board[4].starts_with("  3)     0 ·· "));
+
+        let times = render_times(&leaderboard, 1);
+        assert_eq!(
+            times,
+            vec![
+                "Alice               | part 1:      5m 00s | part 2:  1h 02m 03s",
+                "(anonymous user #2) | part 1:  8h 06m 40s | part 2:           -",
+            ]
+        );
+    }
+}
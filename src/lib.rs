@@ -3,92 +3,529 @@
  * There is no need to edit this file unless you want to change template functionality.
  * Prefer `./helpers.rs` if you want to extract code from your solutions.
  */
+use alloc::AllocStats;
 use std::env;
+use std::error::Error;
+use std::fmt::{self, Display};
 use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::{Duration, Instant};
 
+pub mod alloc;
+pub mod answers;
+pub mod aoc;
+pub mod bench;
+pub mod build;
+pub mod calendar;
+pub mod child;
+pub mod client;
+pub mod days;
 pub mod helpers;
+pub mod history;
+pub mod input_file;
+pub mod leaderboard;
+pub mod puzzle;
+pub mod readme;
+pub mod report;
+pub mod selection;
+pub mod style;
+pub mod submit;
+pub mod template;
+pub mod watch;
 
-pub const ANSI_ITALIC: &str = "\x1b[3m";
-pub const ANSI_BOLD: &str = "\x1b[1m";
-pub const ANSI_RESET: &str = "\x1b[0m";
-
-#[macro_export]
-macro_rules! solve {
-    ($part:expr, $solver:ident, $input:expr) => {{
-        use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-        use std::fmt::Display;
-        use std::time::Instant;
-
-        fn print_result<T: Display>(func: impl FnOnce(&str) -> Option<T>, input: &str) {
-            let timer = Instant::now();
-            let result = func(input);
-            let elapsed = timer.elapsed();
-            match result {
-                Some(result) => {
-                    println!(
-                        "{} {}(elapsed: {:.2?}){}",
-                        result, ANSI_ITALIC, elapsed, ANSI_RESET
-                    );
-                }
-                None => {
-                    println!("not solved.")
-                }
+/// The puzzle year of the solutions in `days`. Inputs, examples and answers are stored per
+/// year, see `data_file`, and `cargo download` fetches this year unless told otherwise.
+pub const YEAR: u16 = 2022;
+
+/// When this environment variable is set, `solve_day` prints machine-readable records
+/// instead of human-readable output and leaves checking answers to the caller.
+pub const RECORD_ENV: &str = "AOC_RECORD_RESULTS";
+const RECORD_PREFIX: &str = "AOC_PART_RESULT";
+const PARSE_RECORD_PREFIX: &str = "AOC_PARSE_RESULT";
+
+/// The outcome of running one part of a solution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartResult {
+    pub part: u8,
+    /// The formatted answer, or `None` if the part is not solved yet.
+    pub answer: Option<String>,
+    pub elapsed: Duration,
+    /// Only counted when built with the `count-allocations` feature, see `alloc`.
+    pub allocs: Option<AllocStats>,
+}
+
+impl PartResult {
+    pub fn is_solved(&self) -> bool {
+        self.answer.is_some()
+    }
+
+    /// Serializes the result into a single line that `from_record` can read back.
+    pub fn to_record(&self) -> String {
+        let (status, answer) = match &self.answer {
+            Some(answer) => ("solved", escape_record(answer)),
+            None => ("unsolved", String::new()),
+        };
+        format!(
+            "{}\t{}\t{}\t{}\t{}\t{}",
+            RECORD_PREFIX,
+            self.part,
+            status,
+            self.elapsed.as_nanos(),
+            allocs_record(&self.allocs),
+            answer
+        )
+    }
+
+    /// Parses a line written by `to_record`. Returns `None` for any other line.
+    pub fn from_record(line: &str) -> Option<Self> {
+        let mut fields = line.splitn(6, '\t');
+        if fields.next()? != RECORD_PREFIX {
+            return None;
+        }
+        let part = fields.next()?.parse().ok()?;
+        let status = fields.next()?;
+        let elapsed = Duration::from_nanos(fields.next()?.parse().ok()?);
+        let allocs = parse_allocs_record(fields.next()?)?;
+        let answer = fields.next()?;
+        let answer = match status {
+            "solved" => Some(unescape_record(answer)),
+            "unsolved" => None,
+            _ => return None,
+        };
+        Some(Self {
+            part,
+            answer,
+            elapsed,
+            allocs,
+        })
+    }
+}
+
+/// The outcome of parsing the input of a two-phase day, see `days`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseResult {
+    pub elapsed: Duration,
+    /// Only counted when built with the `count-allocations` feature, see `alloc`.
+    pub allocs: Option<AllocStats>,
+}
+
+impl ParseResult {
+    /// Serializes the result into a single line that `from_record` can read back.
+    pub fn to_record(&self) -> String {
+        format!(
+            "{}\t{}\t{}",
+            PARSE_RECORD_PREFIX,
+            self.elapsed.as_nanos(),
+            allocs_record(&self.allocs)
+        )
+    }
+
+    /// Parses a line written by `to_record`. Returns `None` for any other line.
+    pub fn from_record(line: &str) -> Option<Self> {
+        let mut fields = line.split('\t');
+        if fields.next()? != PARSE_RECORD_PREFIX {
+            return None;
+        }
+        Some(Self {
+            elapsed: Duration::from_nanos(fields.next()?.parse().ok()?),
+            allocs: parse_allocs_record(fields.next()?)?,
+        })
+    }
+}
+
+fn allocs_record(allocs: &Option<AllocStats>) -> String {
+    match allocs {
+        Some(a) => format!("{}/{}/{}", a.allocations, a.bytes, a.peak),
+        None => "-".into(),
+    }
+}
+
+/// Parses the allocations field of a record. The outer `None` means the field is malformed.
+fn parse_allocs_record(field: &str) -> Option<Option<AllocStats>> {
+    if field == "-" {
+        return Some(None);
+    }
+    let mut allocs = field.split('/').map(|v| v.parse().ok());
+    Some(Some(AllocStats {
+        allocations: allocs.next()??,
+        bytes: allocs.next()??,
+        peak: allocs.next()??,
+    }))
+}
+
+pub(crate) fn escape_record(val: &str) -> String {
+    val.replace('\\', "\\\\")
+        .replace('\t', "\\t")
+        .replace('\n', "\\n")
+}
+
+pub(crate) fn unescape_record(val: &str) -> String {
+    let mut out = String::with_capacity(val.len());
+    let mut chars = val.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('t') => out.push('\t'),
+            Some('n') => out.push('\n'),
+            Some(c) => out.push(c),
+            None => out.push('\\'),
+        }
+    }
+    out
+}
+
+/// Runs `parser` on `input` and times the call, counting its allocations if enabled.
+pub fn run_parse<P>(parser: impl FnOnce(&str) -> P, input: &str) -> (P, ParseResult) {
+    let ((parsed, elapsed), allocs) = alloc::measure(|| {
+        let timer = Instant::now();
+        let parsed = parser(input);
+        (parsed, timer.elapsed())
+    });
+    (parsed, ParseResult { elapsed, allocs })
+}
+
+/// Runs `solver` on `input` and times the call, counting its allocations if enabled.
+/// `input` is either the raw input or, for two-phase days, the value returned by `run_parse`.
+pub fn run_part<I: ?Sized, T: Display>(
+    part: u8,
+    solver: impl FnOnce(&I) -> Option<T>,
+    input: &I,
+) -> PartResult {
+    let ((result, elapsed), allocs) = alloc::measure(|| {
+        let timer = Instant::now();
+        let result = solver(input);
+        (result, timer.elapsed())
+    });
+    PartResult {
+        part,
+        answer: result.map(|r| r.to_string()),
+        elapsed,
+        allocs,
+    }
+}
+
+/// Formats allocation statistics for display, e.g. ` | allocs: 12 | allocated: 1.50 KiB | peak: 800 B`.
+pub fn format_allocs(allocs: &Option<AllocStats>) -> String {
+    match allocs {
+        Some(a) => format!(
+            " | allocs: {} | allocated: {} | peak: {}",
+            a.allocations,
+            alloc::format_bytes(a.bytes),
+            alloc::format_bytes(a.peak)
+        ),
+        None => String::new(),
+    }
+}
+
+pub fn print_result(result: &PartResult) {
+    println!(
+        "🎄 {}Part {}{} 🎄",
+        style::bold(),
+        result.part,
+        style::reset()
+    );
+    match &result.answer {
+        Some(answer) => {
+            println!(
+                "{} {}(elapsed: {:.2?}{}){}",
+                answer,
+                style::italic(),
+                result.elapsed,
+                format_allocs(&result.allocs),
+                style::reset()
+            );
+        }
+        None => {
+            println!("not solved.")
+        }
+    }
+}
+
+pub fn print_parse(result: &ParseResult) {
+    println!(
+        "🎄 {}Parse{} 🎄 {}(elapsed: {:.2?}{}){}",
+        style::bold(),
+        style::reset(),
+        style::italic(),
+        result.elapsed,
+        format_allocs(&result.allocs),
+        style::reset()
+    );
+}
+
+/// Prints `result` either for humans or, when `RECORD_ENV` is set, as a record.
+pub fn report_result(result: &PartResult) {
+    if env::var_os(RECORD_ENV).is_some() {
+        println!("{}", result.to_record());
+    } else {
+        print_result(result);
+    }
+}
+
+/// Prints `result` either for humans or, when `RECORD_ENV` is set, as a record.
+pub fn report_parse(result: &ParseResult) {
+    if env::var_os(RECORD_ENV).is_some() {
+        println!("{}", result.to_record());
+    } else {
+        print_parse(result);
+    }
+}
+
+/// Runs both parts of a day registered in `days`. Used by the `src/bin/DD.rs` binaries.
+/// Pass `--bench` to benchmark the parts, see `bench::Config`, and `--input <path>`,
+/// `--example` or `-` to choose the input, see `Input`.
+/// Exits with a non-zero status if a part does not match its known answer in `answers`.
+pub fn solve_day(day: u8) -> [PartResult; 2] {
+    let mut args = pico_args::Arguments::from_env();
+    let (bench, source) = match style::ColorChoice::from_args(&mut args)
+        .and_then(|_| bench::Config::from_args(&mut args))
+        .and_then(|bench| Ok((bench, Input::from_args(&mut args)?)))
+    {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let solution = match days::get(day) {
+        Some(solution) => solution,
+        None => panic!("day {} is not registered in src/days/mod.rs", day),
+    };
+    // known answers only apply to the puzzle input and are checked by the caller in record mode.
+    let check_answers = source == Input::Puzzle && env::var_os(RECORD_ENV).is_none();
+    let answers = match answers::Answers::load(day) {
+        Ok(answers) if check_answers => answers,
+        Err(e) if check_answers => {
+            eprintln!("Failed to read known answers: {}", e);
+            answers::Answers::default()
+        }
+        _ => answers::Answers::default(),
+    };
+
+    let input = &match source.read(day) {
+        Ok(input) => input,
+        Err(e) if source == Input::Puzzle => {
+            eprintln!("{} ({})", e.describe(day), e);
+            process::exit(1);
+        }
+        Err(e) => {
+            eprintln!("Failed to read input: {}", e);
+            process::exit(1);
+        }
+    };
+    let parsed = match &bench {
+        Some(config) => {
+            let (parsed, parse) = bench::bench_parse(solution, input, config);
+            if let Some((result, stats)) = parse {
+                bench::print_parse(&result, &stats);
+            }
+            parsed
+        }
+        None => {
+            let (parsed, parse) = solution.parse(input);
+            if let Some(result) = parse {
+                report_parse(&result);
             }
+            parsed
         }
+    };
+    let results = [solution.part_one, solution.part_two].map(|part| match &bench {
+        Some(config) => {
+            let (result, stats) = bench::bench_part(part, &parsed, config);
+            bench::print_result(&result, &stats);
+            if check_answers {
+                answers::print_verdict(&answers.verify(&result));
+            }
+            result
+        }
+        None => {
+            let result = part(&parsed);
+            report_result(&result);
+            if check_answers {
+                answers::print_verdict(&answers.verify(&result));
+            }
+            result
+        }
+    });
 
-        println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, $part, ANSI_RESET);
-        print_result($solver, $input);
-    }};
+    let is_wrong = |result| matches!(answers.verify(result), answers::Verdict::Wrong { .. });
+    if results.iter().any(is_wrong) {
+        process::exit(1);
+    }
+    results
 }
 
-pub fn read_file(folder: &str, day: u8) -> String {
-    let cwd = env::current_dir().unwrap();
-
-    let filepath = cwd.join("src").join(folder).join(format!("{:02}.txt", day));
-
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
-}
-
-fn parse_time(val: &str, postfix: &str) -> f64 {
-    val.split(postfix).next().unwrap().parse().unwrap()
-}
-
-pub fn parse_exec_time(output: &str) -> f64 {
-    output.lines().fold(0_f64, |acc, l| {
-        if !l.contains("elapsed:") {
-            acc
-        } else {
-            let timing = l.split("(elapsed: ").last().unwrap();
-            // use `contains` istd. of `ends_with`: string may contain ANSI escape sequences.
-            // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-            if timing.contains("ns)") {
-                acc // range below rounding precision.
-            } else if timing.contains("µs)") {
-                acc + parse_time(timing, "µs") / 1000_f64
-            } else if timing.contains("ms)") {
-                acc + parse_time(timing, "ms")
-            } else if timing.contains("s)") {
-                acc + parse_time(timing, "s") * 1000_f64
+/// The root directory of this crate. Files of the crate are resolved against it, so that they
+/// are found from any working directory.
+pub fn crate_dir() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+/// The `src/` directory of the crate.
+pub fn src_dir() -> PathBuf {
+    crate_dir().join("src")
+}
+
+/// The `target/` directory of the crate.
+pub fn target_dir() -> PathBuf {
+    crate_dir().join("target")
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReadError {
+    Missing(PathBuf),
+    /// The file is still at its location from before files were stored per year.
+    OldLocation {
+        old: PathBuf,
+        new: PathBuf,
+    },
+    Empty(PathBuf),
+    NotUtf8(PathBuf),
+    Io(PathBuf, io::ErrorKind),
+}
+
+impl ReadError {
+    /// A short description for reports, with a hint on how to fix missing inputs.
+    pub fn describe(&self, day: u8) -> String {
+        match self {
+            ReadError::Missing(_) => format!("input missing — run `cargo download {}`", day),
+            ReadError::OldLocation { old, new } => format!(
+                "input is at its old location — move {} to {}",
+                old.display(),
+                new.display()
+            ),
+            ReadError::Empty(_) => format!("input empty — run `cargo download {}`", day),
+            ReadError::NotUtf8(_) => "input is not valid UTF-8".into(),
+            ReadError::Io(_, kind) => format!("could not read input: {}", kind),
+        }
+    }
+}
+
+impl Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReadError::Missing(path) => write!(f, "file {} does not exist", path.display()),
+            ReadError::OldLocation { new, .. } => {
+                write!(f, "file {} does not exist", new.display())
+            }
+            ReadError::Empty(path) => write!(f, "file {} is empty", path.display()),
+            ReadError::NotUtf8(path) => write!(f, "file {} is not valid UTF-8", path.display()),
+            ReadError::Io(path, kind) => write!(f, "could not open {}: {}", path.display(), kind),
+        }
+    }
+}
+
+impl Error for ReadError {}
+
+fn read_contents(path: PathBuf, contents: io::Result<String>) -> Result<String, ReadError> {
+    match contents {
+        Ok(contents) if contents.is_empty() => Err(ReadError::Empty(path)),
+        Ok(contents) => Ok(contents),
+        Err(e) => Err(match e.kind() {
+            io::ErrorKind::NotFound => ReadError::Missing(path),
+            io::ErrorKind::InvalidData => ReadError::NotUtf8(path),
+            kind => ReadError::Io(path, kind),
+        }),
+    }
+}
+
+/// The file of `day` in `folder`, relative to `src_dir`, e.g. `inputs/2022/01.txt`.
+pub fn data_file(folder: &str, year: u16, day: u8) -> PathBuf {
+    Path::new(folder)
+        .join(year.to_string())
+        .join(format!("{:02}.txt", day))
+}
+
+/// Reads the file of `day` in `folder` of `root` for `year`.
+fn read_data_file(root: &Path, folder: &str, year: u16, day: u8) -> Result<String, ReadError> {
+    let filepath = root.join(data_file(folder, year, day));
+    let contents = fs::read_to_string(&filepath);
+    match read_contents(filepath, contents) {
+        // files used to be stored without a year folder, e.g. `src/inputs/01.txt`.
+        Err(ReadError::Missing(new)) => {
+            let old = root.join(folder).join(format!("{:02}.txt", day));
+            if old.is_file() {
+                Err(ReadError::OldLocation { old, new })
             } else {
-                acc
+                Err(ReadError::Missing(new))
             }
         }
-    })
+        result => result,
+    }
 }
 
-/// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-#[cfg(test)]
-macro_rules! assert_approx_eq {
-    ($a:expr, $b:expr) => {{
-        let (a, b) = (&$a, &$b);
-        assert!(
-            (*a - *b).abs() < 1.0e-6,
-            "{} is not approximately equal to {}",
-            *a,
-            *b
-        );
-    }};
+/// Reads the file of `day` in `folder` for the current `YEAR`.
+pub fn try_read_file(folder: &str, day: u8) -> Result<String, ReadError> {
+    read_data_file(&src_dir(), folder, YEAR, day)
+}
+
+/// Like `try_read_file`, but panics if the file cannot be read. Empty files are allowed.
+pub fn read_file(folder: &str, day: u8) -> String {
+    match try_read_file(folder, day) {
+        Ok(contents) => contents,
+        Err(ReadError::Empty(_)) => String::new(),
+        Err(e) => panic!("could not open input file: {}", e),
+    }
+}
+
+/// Where a day binary reads its input from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Input {
+    /// `src/inputs/YYYY/DD.txt`, the default.
+    Puzzle,
+    /// `src/examples/YYYY/DD.txt`, selected with `--example`.
+    Example,
+    /// Any file, selected with `--input <path>`.
+    File(PathBuf),
+    /// Standard input, selected with `-`.
+    Stdin,
+}
+
+impl Input {
+    pub fn from_args(args: &mut pico_args::Arguments) -> Result<Self, pico_args::Error> {
+        let example = args.contains("--example");
+        let file: Option<PathBuf> = args.opt_value_from_os_str("--input", |s| {
+            Ok::<_, std::convert::Infallible>(PathBuf::from(s))
+        })?;
+        let stdin = match args.opt_free_from_str::<String>()? {
+            Some(arg) if arg == "-" => true,
+            Some(arg) => {
+                return Err(pico_args::Error::ArgumentParsingFailed {
+                    cause: format!("unexpected argument \"{}\"", arg),
+                })
+            }
+            None => false,
+        };
+
+        match (example, file, stdin) {
+            (false, None, false) => Ok(Input::Puzzle),
+            (true, None, false) => Ok(Input::Example),
+            (false, Some(file), false) => Ok(Input::File(file)),
+            (false, None, true) => Ok(Input::Stdin),
+            _ => Err(pico_args::Error::ArgumentParsingFailed {
+                cause: "use only one of --input, --example and -".into(),
+            }),
+        }
+    }
+
+    pub fn read(&self, day: u8) -> Result<String, ReadError> {
+        match self {
+            Input::Puzzle => try_read_file("inputs", day),
+            Input::Example => try_read_file("examples", day),
+            Input::File(path) => read_contents(path.clone(), fs::read_to_string(path)),
+            Input::Stdin => {
+                let mut contents = String::new();
+                let result = io::stdin().read_to_string(&mut contents).map(|_| contents);
+                read_contents(PathBuf::from("-"), result)
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -96,30 +533,138 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_parse_exec_time() {
-        assert_approx_eq!(
-            parse_exec_time(&format!(
-                "🎄 Part 1 🎄\n0 (elapsed: 74.13ns){}\n🎄 Part 2 🎄\n0 (elapsed: 50.00ns){}",
-                ANSI_RESET, ANSI_RESET
-            )),
-            0_f64
+    fn test_data_file() {
+        assert_eq!(
+            data_file("inputs", 2021, 7),
+            Path::new("inputs").join("2021").join("07.txt")
         );
+    }
+
+    #[test]
+    fn test_read_data_file_at_old_location() {
+        let root = env::temp_dir().join(format!("aoc-old-location-{}", std::process::id()));
+        fs::create_dir_all(root.join("inputs")).unwrap();
+        fs::write(root.join("inputs").join("03.txt"), "abc").unwrap();
 
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 755µs)\n🎄 Part 2 🎄\n0 (elapsed: 700µs)"),
-            1.455_f64
+        match read_data_file(&root, "inputs", 2022, 3) {
+            Err(ReadError::OldLocation { old, new }) => {
+                assert_eq!(old, root.join("inputs").join("03.txt"));
+                assert_eq!(new, root.join("inputs").join("2022").join("03.txt"));
+            }
+            result => panic!("expected OldLocation, got {:?}", result),
+        }
+        assert!(matches!(
+            read_data_file(&root, "inputs", 2022, 4),
+            Err(ReadError::Missing(_))
+        ));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_try_read_file() {
+        assert!(try_read_file("examples", 1).unwrap().starts_with("1000"));
+        assert!(matches!(
+            try_read_file("examples", 99),
+            Err(ReadError::Missing(_))
+        ));
+        assert_eq!(
+            ReadError::Missing(PathBuf::new()).describe(7),
+            "input missing — run `cargo download 7`"
         );
+    }
 
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 70µs)\n🎄 Part 2 🎄\n0 (elapsed: 1.45ms)"),
-            1.52_f64
+    #[test]
+    fn test_input_from_args() {
+        let parse = |args: &[&str]| {
+            let mut args = pico_args::Arguments::from_vec(args.iter().map(|a| a.into()).collect());
+            Input::from_args(&mut args)
+        };
+        assert_eq!(parse(&[]).unwrap(), Input::Puzzle);
+        assert_eq!(parse(&["--example"]).unwrap(), Input::Example);
+        assert_eq!(parse(&["-"]).unwrap(), Input::Stdin);
+        assert_eq!(
+            parse(&["--input", "big.txt"]).unwrap(),
+            Input::File("big.txt".into())
         );
+        assert!(parse(&["--example", "-"]).is_err());
+        assert!(parse(&["big.txt"]).is_err());
+    }
 
-        assert_approx_eq!(
-            parse_exec_time(
-                "🎄 Part 1 🎄\n0 (elapsed: 10.3s)\n🎄 Part 2 🎄\n0 (elapsed: 100.50ms)"
-            ),
-            10400.50_f64
+    #[test]
+    fn test_input_read() {
+        let example = src_dir().join(data_file("examples", YEAR, 5));
+        assert_eq!(Input::File(example).read(5), Input::Example.read(5));
+        assert!(matches!(
+            Input::File("does/not/exist.txt".into()).read(5),
+            Err(ReadError::Missing(_))
+        ));
+    }
+
+    #[test]
+    fn test_record_roundtrip() {
+        let solved = PartResult {
+            part: 1,
+            answer: Some("CMZ".into()),
+            elapsed: Duration::from_nanos(74),
+            allocs: None,
+        };
+        assert_eq!(PartResult::from_record(&solved.to_record()), Some(solved));
+
+        let unsolved = PartResult {
+            part: 2,
+            answer: None,
+            elapsed: Duration::from_micros(700),
+            allocs: Some(AllocStats {
+                allocations: 3,
+                bytes: 384,
+                peak: 256,
+            }),
+        };
+        assert_eq!(
+            PartResult::from_record(&unsolved.to_record()),
+            Some(unsolved)
         );
     }
+
+    #[test]
+    fn test_record_escaping() {
+        let multiline = PartResult {
+            part: 2,
+            answer: Some("##..\n.#\t\\".into()),
+            elapsed: Duration::from_secs(10),
+            allocs: None,
+        };
+        let record = multiline.to_record();
+        assert_eq!(record.lines().count(), 1);
+        assert_eq!(PartResult::from_record(&record), Some(multiline));
+    }
+
+    #[test]
+    fn test_record_ignores_other_lines() {
+        assert_eq!(PartResult::from_record("#####....."), None);
+        assert_eq!(PartResult::from_record("🎄 Part 1 🎄"), None);
+    }
+
+    #[test]
+    fn test_parse_record_roundtrip() {
+        for result in [
+            ParseResult {
+                elapsed: Duration::from_nanos(900),
+                allocs: None,
+            },
+            ParseResult {
+                elapsed: Duration::from_micros(3),
+                allocs: Some(AllocStats {
+                    allocations: 2,
+                    bytes: 96,
+                    peak: 64,
+                }),
+            },
+        ] {
+            let record = result.to_record();
+            assert_eq!(ParseResult::from_record(&record), Some(result));
+            assert_eq!(PartResult::from_record(&record), None);
+        }
+    }
 }
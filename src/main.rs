@@ -2,45 +2,400 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use advent_of_code::answers::{self, Answers, Verdict};
+use advent_of_code::bench::{self, Stats};
+use advent_of_code::build::{Build, Profile};
+use advent_of_code::child::{self, ChildOutcome};
+use advent_of_code::days::{self, Solution};
+use advent_of_code::history::{self, Entry, History};
+use advent_of_code::report::{self, DayReport, DayStatus, Format, PartReport};
+use advent_of_code::selection::Selection;
+use advent_of_code::style::{self, ColorChoice};
+use advent_of_code::{ParseResult, PartResult, RECORD_ENV};
+use std::cell::RefCell;
+use std::path::PathBuf;
 use std::process::Command;
+use std::time::Duration;
+use std::{env, panic, process};
+
+/// Runs a single day in a child process, see `run_isolated`.
+const CHILD_FLAG: &str = "--run-day";
+
+thread_local! {
+    static LAST_PANIC: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+struct Args {
+    format: Format,
+    bench: Option<bench::Config>,
+    history: PathBuf,
+    threshold: f64,
+    verify: bool,
+    timeout: Option<Duration>,
+    /// Build the days once with this profile and run their binaries, see `build`.
+    profile: Option<Profile>,
+    selection: Selection,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    ColorChoice::from_args(&mut args)?;
+    Ok(Args {
+        format: args.opt_value_from_str("--format")?.unwrap_or(Format::Text),
+        bench: bench::Config::from_args(&mut args)?,
+        history: args
+            .opt_value_from_str("--history")?
+            .unwrap_or_else(history::default_path),
+        threshold: args
+            .opt_value_from_str("--threshold")?
+            .unwrap_or(history::DEFAULT_THRESHOLD),
+        verify: args.contains("--verify"),
+        timeout: args.opt_value_from_fn("--timeout", |s| s.parse().map(Duration::from_secs_f64))?,
+        profile: args.opt_value_from_str("--profile")?,
+        // free arguments have to be read last.
+        selection: Selection::from_args(&mut args)?,
+    })
+}
+
+type Parts = Vec<(PartResult, Option<Stats>)>;
+
+/// The parse step of a two-phase day and the parts.
+type Run = (Option<ParseResult>, Parts);
+
+fn run_in_process(
+    solution: &Solution,
+    input: &str,
+    bench: Option<&bench::Config>,
+) -> Result<Run, DayStatus> {
+    // panics of a day are shown in the report, capture their messages instead of printing them.
+    // the previous hook is restored afterwards, so that panics of the runner itself are printed.
+    let previous_hook = panic::take_hook();
+    panic::set_hook(Box::new(|info| {
+        LAST_PANIC.with(|p| *p.borrow_mut() = Some(info.to_string()));
+    }));
+
+    // a panicking solution should not abort the remaining days.
+    let run = panic::catch_unwind(|| {
+        let (parsed, parse) = match bench {
+            Some(config) => {
+                let (parsed, parse) = bench::bench_parse(solution, input, config);
+                (parsed, parse.map(|(result, _)| result))
+            }
+            None => solution.parse(input),
+        };
+        let parts = [solution.part_one, solution.part_two].map(|part| match bench {
+            Some(config) => {
+                let (result, stats) = bench::bench_part(part, &parsed, config);
+                (result, Some(stats))
+            }
+            None => (part(&parsed), None),
+        });
+        (parse, Vec::from(parts))
+    });
+    panic::set_hook(previous_hook);
+
+    run.map_err(|_| {
+        let message = LAST_PANIC.with(|p| p.borrow_mut().take());
+        DayStatus::Panicked(message.unwrap_or_default())
+    })
+}
+
+/// Runs the day in a child process of this executable so that it can be killed after `timeout`.
+fn run_isolated(solution: &Solution, timeout: Duration) -> Result<Run, DayStatus> {
+    let mut cmd = Command::new(env::current_exe().expect("could not locate runner executable"));
+    cmd.args([CHILD_FLAG, &solution.day.to_string()]);
+    run_records(cmd, timeout)
+}
+
+/// Runs the binary of the day that was built by `Build`, killing it after `timeout` if set.
+fn run_binary(build: &Build, day: u8, timeout: Option<Duration>) -> Result<Run, DayStatus> {
+    let executable = build.executable(day).map_err(DayStatus::BuildFailed)?;
+    let mut cmd = Command::new(executable);
+    cmd.env(RECORD_ENV, "1");
+    run_records(cmd, timeout.unwrap_or(Duration::MAX))
+}
+
+/// Runs `cmd` and reads the records it prints, see `PartResult::to_record`.
+fn run_records(cmd: Command, timeout: Duration) -> Result<Run, DayStatus> {
+    match child::run_with_timeout(cmd, timeout) {
+        Ok(ChildOutcome::Exited {
+            success: true,
+            stdout,
+            ..
+        }) => Ok((
+            stdout.lines().find_map(ParseResult::from_record),
+            stdout
+                .lines()
+                .filter_map(PartResult::from_record)
+                .map(|result| (result, None))
+                .collect(),
+        )),
+        Ok(ChildOutcome::Exited { stderr, .. }) => {
+            Err(DayStatus::Panicked(stderr.trim().to_string()))
+        }
+        Ok(ChildOutcome::TimedOut) => Err(DayStatus::TimedOut(timeout)),
+        Err(e) => Err(DayStatus::Panicked(format!("could not run day: {}", e))),
+    }
+}
+
+/// The entry point of the child processes started by `run_isolated`.
+fn run_child(day: u8) {
+    let solution = days::get(day).expect("day is not registered");
+    let input = &advent_of_code::read_file("inputs", day);
+    let (parsed, parse) = solution.parse(input);
+    if let Some(result) = parse {
+        println!("{}", result.to_record());
+    }
+    for part in [solution.part_one, solution.part_two] {
+        println!("{}", part(&parsed).to_record());
+    }
+}
+
+fn run_solution(solution: &Solution, args: &Args, build: Option<&Build>) -> DayReport {
+    let input = &match advent_of_code::try_read_file("inputs", solution.day) {
+        Ok(input) => input,
+        Err(e) => return DayReport::new(solution.day, DayStatus::Input(e)),
+    };
+
+    let run = match (build, args.timeout) {
+        (Some(build), timeout) => run_binary(build, solution.day, timeout),
+        (None, Some(timeout)) => run_isolated(solution, timeout),
+        (None, None) => run_in_process(solution, input, args.bench.as_ref()),
+    };
+    let (parse, parts) = match run {
+        Ok(run) => run,
+        Err(status) => return DayReport::new(solution.day, status),
+    };
+
+    let answers = Answers::load(solution.day).unwrap_or_else(|e| {
+        eprintln!(
+            "Failed to read known answers of day {}: {}",
+            solution.day, e
+        );
+        Answers::default()
+    });
+
+    DayReport {
+        day: solution.day,
+        status: DayStatus::Completed,
+        parse,
+        parts: parts
+            .into_iter()
+            .map(|(result, bench)| PartReport {
+                verdict: answers.verify(&result),
+                result,
+                bench,
+            })
+            .collect(),
+    }
+}
+
+fn print_day(report: &DayReport, history: Option<&History>, threshold: f64) {
+    println!("----------");
+    println!(
+        "{}| Day {:02} |{}",
+        style::bold(),
+        report.day,
+        style::reset()
+    );
+    println!("----------");
+
+    match &report.status {
+        DayStatus::Completed => (),
+        DayStatus::NotSolved => println!("Not solved."),
+        DayStatus::Input(e) => println!(
+            "{}{}.{}",
+            style::italic(),
+            e.describe(report.day),
+            style::reset()
+        ),
+        DayStatus::Panicked(message) => {
+            println!("{}Panicked:{}", style::bold(), style::reset());
+            println!("{}", message);
+        }
+        DayStatus::BuildFailed(errors) => {
+            println!("{}Build failed:{}", style::bold(), style::reset());
+            println!("{}", errors);
+        }
+        DayStatus::TimedOut(timeout) => println!(
+            "{}Timed out{} after {:.2?}.",
+            style::bold(),
+            style::reset(),
+            timeout
+        ),
+    }
+
+    if let Some(parse) = &report.parse {
+        advent_of_code::print_parse(parse);
+    }
+    for PartReport {
+        result,
+        bench,
+        verdict,
+    } in &report.parts
+    {
+        match bench {
+            Some(stats) => bench::print_result(result, stats),
+            None => advent_of_code::print_result(result),
+        }
+        answers::print_verdict(verdict);
+        if let (Some(history), true) = (history, result.is_solved()) {
+            history::print_comparison(history, report.day, result.part, result.elapsed, threshold);
+        }
+    }
+}
+
+/// Prints one line per part of the solved days and a summary, used by `cargo verify`.
+fn print_verification(reports: &[DayReport]) {
+    let parts = reports
+        .iter()
+        .flat_map(|report| report.parts.iter().map(move |part| (report.day, part)));
+
+    let mut counts = [0; 3];
+    for (day, part) in parts {
+        let (mark, count) = match &part.verdict {
+            Verdict::Correct => ("✔", &mut counts[0]),
+            Verdict::Wrong { .. } => ("✘", &mut counts[1]),
+            Verdict::Unknown => ("?", &mut counts[2]),
+        };
+        *count += 1;
+        print!("Day {:02} | Part {} | {} ", day, part.result.part, mark);
+        match &part.verdict {
+            Verdict::Wrong { expected } => println!(
+                "got {}, expected {}",
+                part.result.answer.as_deref().unwrap_or("nothing"),
+                expected
+            ),
+            _ => println!("{}", part.verdict.as_str()),
+        }
+    }
+
+    println!(
+        "{}Verified:{} {} correct, {} wrong, {} unknown",
+        style::bold(),
+        style::reset(),
+        counts[0],
+        counts[1],
+        counts[2]
+    );
+}
 
 fn main() {
-    let total: f64 = (1..=25)
+    let mut child_args = pico_args::Arguments::from_env();
+    if let Ok(Some(day)) = child_args.opt_value_from_str(CHILD_FLAG) {
+        run_child(day);
+        return;
+    }
+
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    if args.bench.is_some() && (args.timeout.is_some() || args.profile.is_some()) {
+        eprintln!("--bench can not be combined with --timeout or --profile.");
+        process::exit(1);
+    }
+
+    // timings are only comparable between optimized builds, so only those are recorded.
+    let optimized = match &args.profile {
+        Some(profile) => profile.is_optimized(),
+        None => !cfg!(debug_assertions),
+    };
+    let history = if !optimized {
+        None
+    } else {
+        match History::load(&args.history) {
+            Ok(history) => Some(history),
+            Err(e) => {
+                eprintln!("Failed to read benchmark history: {}", e);
+                None
+            }
+        }
+    };
+    let run = history::current_run();
+
+    let build = args.profile.as_ref().map(|profile| {
+        let days = args
+            .selection
+            .days()
+            .filter(|day| days::get(*day).is_some())
+            .collect::<Vec<_>>();
+        eprintln!(
+            "Building {} days with profile \"{}\"...",
+            days.len(),
+            profile.name()
+        );
+        Build::run(profile, &days).unwrap_or_else(|e| {
+            eprintln!("Failed to run cargo build: {}", e);
+            process::exit(1);
+        })
+    });
+
+    let reports = args
+        .selection
+        .days()
         .map(|day| {
-            let day = format!("{:02}", day);
-
-            let cmd = Command::new("cargo")
-                .args(["run", "--release", "--bin", &day])
-                .output()
-                .unwrap();
-
-            println!("----------");
-            println!("{}| Day {} |{}", ANSI_BOLD, day, ANSI_RESET);
-            println!("----------");
-
-            let output = String::from_utf8(cmd.stdout).unwrap();
-            let is_empty = output.is_empty();
-
-            println!(
-                "{}",
-                if is_empty {
-                    "Not solved."
-                } else {
-                    output.trim()
-                }
-            );
-
-            if is_empty {
-                0_f64
-            } else {
-                advent_of_code::parse_exec_time(&output)
+            let report = match days::get(day) {
+                Some(solution) => run_solution(solution, &args, build.as_ref()),
+                None => DayReport::new(day, DayStatus::NotSolved),
+            };
+            if args.format == Format::Text && !args.verify {
+                print_day(&report, history.as_ref(), args.threshold);
             }
+            report
+        })
+        .collect::<Vec<_>>();
+
+    if history.is_some() {
+        let entries = reports
+            .iter()
+            .flat_map(|report| report.parts.iter().map(move |p| (report.day, &p.result)))
+            .filter(|(_, result)| result.is_solved())
+            .map(|(day, result)| Entry {
+                run,
+                day,
+                part: result.part,
+                elapsed: result.elapsed,
+            })
+            .collect::<Vec<_>>();
+
+        if let Err(e) = History::append(&args.history, &entries) {
+            eprintln!("Failed to write benchmark history: {}", e);
+        }
+    }
+
+    let total: Duration = reports
+        .iter()
+        .flat_map(|report| {
+            let parse = report.parse.iter().map(|parse| parse.elapsed);
+            parse.chain(report.parts.iter().map(|part| part.result.elapsed))
         })
         .sum();
 
-    println!(
-        "{}Total:{} {}{:.2}ms{}",
-        ANSI_BOLD, ANSI_RESET, ANSI_ITALIC, total, ANSI_RESET
-    );
+    match args.format {
+        Format::Text if args.verify => print_verification(&reports),
+        Format::Text => println!(
+            "{}Total:{} {}{:.2}ms{}",
+            style::bold(),
+            style::reset(),
+            style::italic(),
+            total.as_secs_f64() * 1000_f64,
+            style::reset()
+        ),
+        Format::Json => println!("{}", report::to_json(&reports, total)),
+        Format::Csv => print!("{}", report::to_csv(&reports, total)),
+    }
+
+    let has_wrong_answer = reports
+        .iter()
+        .flat_map(|report| &report.parts)
+        .any(|part| matches!(part.verdict, Verdict::Wrong { .. }));
+    let has_failed_day = reports.iter().any(|report| report.status.is_failure());
+    if has_wrong_answer || has_failed_day {
+        process::exit(1);
+    }
 }
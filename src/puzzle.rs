@@ -0,0 +1,322 @@
+/*
+ * Reads the HTML of a puzzle page from adventofcode.com, used by `cargo scaffold`:
+ * converts the puzzle descriptions, the `<article>` elements of the page, into
+ * markdown and picks the example input and its expected answer out of them.
+ */
+
+const SITE: &str = "https://adventofcode.com";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token<'a> {
+    /// An opening tag with its name and the raw attributes.
+    Open(&'a str, &'a str),
+    Close(&'a str),
+    Text(String),
+}
+
+pub fn decode_entities(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let entity = rest.find(';').map(|end| (&rest[1..end], end));
+        let decoded = entity.and_then(|(name, end)| {
+            let c = match name {
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "amp" => Some('&'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                "nbsp" => Some(' '),
+                _ => match name.strip_prefix("#x").or_else(|| name.strip_prefix("#X")) {
+                    Some(hex) => u32::from_str_radix(hex, 16).ok().and_then(char::from_u32),
+                    None => name
+                        .strip_prefix('#')
+                        .and_then(|dec| dec.parse().ok())
+                        .and_then(char::from_u32),
+                },
+            };
+            c.map(|c| (c, end))
+        });
+        match decoded {
+            Some((c, end)) => {
+                out.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+fn tokenize(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = vec![];
+    let mut rest = html;
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            tokens.push(Token::Text(decode_entities(rest)));
+            break;
+        };
+        if start > 0 {
+            tokens.push(Token::Text(decode_entities(&rest[..start])));
+        }
+        let Some(end) = rest[start..].find('>') else {
+            tokens.push(Token::Text(decode_entities(&rest[start..])));
+            break;
+        };
+        let tag = &rest[start + 1..start + end];
+        rest = &rest[start + end + 1..];
+
+        if let Some(name) = tag.strip_prefix('/') {
+            tokens.push(Token::Close(name.trim()));
+        } else if !tag.starts_with('!') {
+            let tag = tag.trim_end_matches('/');
+            let (name, attrs) = tag.split_once(char::is_whitespace).unwrap_or((tag, ""));
+            tokens.push(Token::Open(name, attrs));
+        }
+    }
+    tokens
+}
+
+/// Reads an attribute like `href="/2022/day/5/input"` from the raw attributes of a tag.
+pub fn attribute<'a>(attrs: &'a str, name: &str) -> Option<&'a str> {
+    let start = attrs.find(&format!("{}=\"", name))? + name.len() + 2;
+    let end = attrs[start..].find('"')?;
+    Some(&attrs[start..start + end])
+}
+
+/// The contents of all `<article>` elements of a puzzle page.
+pub fn articles(html: &str) -> Vec<&str> {
+    let mut articles = vec![];
+    let mut rest = html;
+    while let Some(start) = rest.find("<article") {
+        let Some(open_end) = rest[start..].find('>') else {
+            break;
+        };
+        let content = &rest[start + open_end + 1..];
+        let end = content.find("</article>").unwrap_or(content.len());
+        articles.push(&content[..end]);
+        rest = &content[end..];
+    }
+    articles
+}
+
+/// Makes sure that the output ends with at least `count` line breaks, unless it is empty.
+fn break_lines(out: &mut String, count: usize) {
+    if out.is_empty() {
+        return;
+    }
+    let existing = out.chars().rev().take_while(|c| *c == '\n').count();
+    for _ in existing..count {
+        out.push('\n');
+    }
+}
+
+fn convert(html: &str) -> String {
+    let tokens = tokenize(html);
+    let mut out = String::new();
+    let mut in_pre = false;
+    let mut links = vec![];
+    // emphasized inline code, `<code><em>24000</em></code>`, becomes **`24000`**.
+    let mut strong_code = false;
+
+    for (i, token) in tokens.iter().enumerate() {
+        let next = tokens.get(i + 1);
+        match token {
+            Token::Open("h2", _) => {
+                break_lines(&mut out, 2);
+                out.push_str("## ");
+            }
+            Token::Close("h2") | Token::Close("p") | Token::Close("ul") => break_lines(&mut out, 2),
+            Token::Open("p", _) | Token::Open("ul", _) => break_lines(&mut out, 2),
+            Token::Open("pre", _) => {
+                break_lines(&mut out, 2);
+                out.push_str("```\n");
+                in_pre = true;
+            }
+            Token::Close("pre") => {
+                break_lines(&mut out, 1);
+                out.push_str("```\n\n");
+                in_pre = false;
+            }
+            Token::Open("li", _) => {
+                break_lines(&mut out, 1);
+                out.push_str("- ");
+            }
+            Token::Close("li") => break_lines(&mut out, 1),
+            Token::Open("code", _) if in_pre => (),
+            Token::Close("code") if in_pre => (),
+            Token::Open("code", _) => match next {
+                Some(Token::Open("em", _)) => {
+                    out.push_str("**`");
+                    strong_code = true;
+                }
+                _ => out.push('`'),
+            },
+            Token::Close("code") if strong_code => strong_code = false,
+            Token::Close("code") => out.push('`'),
+            Token::Close("em") if strong_code && matches!(next, Some(Token::Close("code"))) => {
+                out.push_str("`**")
+            }
+            Token::Open("em", _) | Token::Close("em") if in_pre || strong_code => (),
+            Token::Open("em", _) | Token::Close("em") => out.push('*'),
+            Token::Open("a", attrs) => {
+                let href = attribute(attrs, "href").unwrap_or_default();
+                links.push(if href.starts_with('/') {
+                    format!("{}{}", SITE, href)
+                } else {
+                    href.to_string()
+                });
+                out.push('[');
+            }
+            Token::Close("a") => {
+                out.push_str(&format!("]({})", links.pop().unwrap_or_default()));
+            }
+            Token::Text(text) if in_pre => out.push_str(text),
+            Token::Text(text) => {
+                let text = text.replace('\n', " ");
+                // whitespace between block elements would otherwise start lines with a space.
+                if !(text.trim().is_empty() && (out.is_empty() || out.ends_with('\n'))) {
+                    out.push_str(&text);
+                }
+            }
+            _ => (),
+        }
+    }
+    out
+}
+
+/// The text of the `<pre><code>` blocks in the puzzle descriptions, which hold the example inputs.
+pub fn code_blocks(html: &str) -> Vec<String> {
+    let mut blocks = vec![];
+    for article in articles(html) {
+        let mut block: Option<String> = None;
+        for token in tokenize(article) {
+            match (&mut block, token) {
+                (None, Token::Open("pre", _)) => block = Some(String::new()),
+                (Some(_), Token::Close("pre")) => blocks.extend(block.take()),
+                (Some(text), Token::Text(t)) => text.push_str(&t),
+                _ => (),
+            }
+        }
+    }
+    blocks
+        .into_iter()
+        .map(|block| block.trim_end_matches('\n').to_string())
+        .collect()
+}
+
+/// The answer to the example of part one: the last emphasized code, `<code><em>24000</em></code>`,
+/// of the first puzzle description.
+pub fn example_answer(html: &str) -> Option<String> {
+    let tokens = tokenize(articles(html).first()?);
+    tokens
+        .windows(4)
+        .rev()
+        .filter_map(|window| match window {
+            [Token::Open("code", _), Token::Open("em", _), Token::Text(answer), Token::Close("em")]
+            | [Token::Open("em", _), Token::Open("code", _), Token::Text(answer), Token::Close("code")] => {
+                Some(answer.trim().to_string())
+            }
+            _ => None,
+        })
+        .find(|answer| !answer.is_empty())
+}
+
+/// Converts a puzzle page into markdown. Returns `None` if the page has no puzzle description.
+pub fn to_markdown(html: &str) -> Option<String> {
+    let articles = articles(html);
+    if articles.is_empty() {
+        return None;
+    }
+    let markdown = articles
+        .iter()
+        .map(|article| convert(article).trim().to_string())
+        .collect::<Vec<_>>()
+        .join("\n\n");
+    Some(format!("{}\n", markdown))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 1: Calorie Counting ---</h2><p>The Elves take turns writing down the number of <em>Calories</em> (see <a href="/2022/about">about</a>):</p>
+<pre><code>1000
+2000
+
+<em>4000</em>
+</code></pre>
+<ul>
+<li>The first Elf is carrying <code>6000</code> Calories.</li>
+<li>The second &amp; third Elf &lt;3.</li>
+</ul>
+<p>In total, that is <code><em>24000</em></code> Calories.</p>
+</article>
+<p>Your puzzle answer was <code>70509</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Find the <em>top three</em>.</p></article>
+</main></body></html>"#;
+
+    #[test]
+    fn test_to_markdown() {
+        assert_eq!(
+            to_markdown(PAGE).unwrap(),
+            concat!(
+                "## --- Day 1: Calorie Counting ---\n\n",
+                "The Elves take turns writing down the number of *Calories* (see [about](https://adventofcode.com/2022/about)):\n\n",
+                "```\n1000\n2000\n\n4000\n```\n\n",
+                "- The first Elf is carrying `6000` Calories.\n",
+                "- The second & third Elf <3.\n\n",
+                "In total, that is **`24000`** Calories.\n\n",
+                "## --- Part Two ---\n\n",
+                "Find the *top three*.\n"
+            )
+        );
+    }
+
+    #[test]
+    fn test_code_blocks() {
+        assert_eq!(code_blocks(PAGE), vec!["1000\n2000\n\n4000"]);
+        assert!(code_blocks("<p>no puzzle</p>").is_empty());
+    }
+
+    #[test]
+    fn test_example_answer() {
+        assert_eq!(example_answer(PAGE), Some("24000".into()));
+        assert_eq!(
+            example_answer(
+                "<article><p><em><code>CMZ</code></em> and <code>6000</code></p></article>"
+            ),
+            Some("CMZ".into())
+        );
+        assert_eq!(example_answer("<article><p>nothing</p></article>"), None);
+    }
+
+    #[test]
+    fn test_not_a_puzzle() {
+        assert_eq!(to_markdown("--- Day 1: Calorie Counting ---"), None);
+    }
+
+    #[test]
+    fn test_decode_entities() {
+        assert_eq!(
+            decode_entities("a &lt;b&gt; &amp;&#39;&#x41; & c;"),
+            "a <b> &'A & c;"
+        );
+    }
+
+    #[test]
+    fn test_attribute() {
+        assert_eq!(
+            attribute(r#"class="x" href="/2022/day/1""#, "href"),
+            Some("/2022/day/1")
+        );
+        assert_eq!(attribute(r#"class="x""#, "href"), None);
+    }
+}
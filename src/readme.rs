@@ -0,0 +1,195 @@
+/*
+ * The stars table of the README, generated by `cargo readme` between two markers:
+ *
+ *     <!--- advent_readme_stars table --->
+ *     ...
+ *     <!--- advent_readme_stars table end --->
+ *
+ * Everything else in the README is left as it is. If there is only the start marker,
+ * the end marker is added after it.
+ */
+use crate::answers::Answers;
+use crate::history::History;
+use std::time::Duration;
+
+pub const START_MARKER: &str = "<!--- advent_readme_stars table --->";
+pub const END_MARKER: &str = "<!--- advent_readme_stars table end --->";
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Row {
+    pub day: u8,
+    /// Whether the answer of each part is known.
+    pub stars: [bool; 2],
+    /// The elapsed time of each part in the latest release run.
+    pub elapsed: [Option<Duration>; 2],
+}
+
+impl Row {
+    pub fn new(day: u8, answers: &Answers, history: &History) -> Self {
+        Self {
+            day,
+            stars: [1, 2].map(|part| answers.get(part).is_some()),
+            elapsed: [1, 2].map(|part| history.previous(day, part)),
+        }
+    }
+
+    /// Whether the row has anything to show.
+    pub fn is_empty(&self) -> bool {
+        self.stars == [false, false] && self.elapsed == [None, None]
+    }
+}
+
+fn cell(star: bool, elapsed: Option<Duration>) -> String {
+    match (star, elapsed) {
+        (true, Some(elapsed)) => format!("⭐ `{:.2?}`", elapsed),
+        (true, None) => "⭐".into(),
+        (false, Some(elapsed)) => format!("`{:.2?}`", elapsed),
+        (false, None) => String::new(),
+    }
+}
+
+pub fn render_table(year: u16, rows: &[Row]) -> String {
+    let stars = rows
+        .iter()
+        .flat_map(|row| row.stars)
+        .filter(|star| *star)
+        .count();
+    let mut table = format!(
+        "## {} Results\n\n⭐ {} of 50\n\n| Day | Part 1 | Part 2 |\n| :---: | :---: | :---: |\n",
+        year, stars
+    );
+    for row in rows {
+        table.push_str(&format!(
+            "| [Day {}](https://adventofcode.com/{}/day/{}) | {} | {} |\n",
+            row.day,
+            year,
+            row.day,
+            cell(row.stars[0], row.elapsed[0]),
+            cell(row.stars[1], row.elapsed[1])
+        ));
+    }
+    table
+}
+
+/// The start and end of the first line at or after `from` that consists of only `marker`.
+/// Markers that are quoted in the text, like in the docs of this command, do not count.
+fn find_marker(readme: &str, marker: &str, from: usize) -> Option<(usize, usize)> {
+    let mut offset = from;
+    for line in readme[from..].split_inclusive('\n') {
+        if line.trim() == marker {
+            return Some((offset, offset + line.trim_end().len()));
+        }
+        offset += line.len();
+    }
+    None
+}
+
+/// Replaces the region between the markers of `readme` with `table`.
+pub fn replace_table(readme: &str, table: &str) -> Result<String, String> {
+    let (_, start) = find_marker(readme, START_MARKER, 0)
+        .ok_or_else(|| format!("the README has no \"{}\" line", START_MARKER))?;
+    let end = find_marker(readme, END_MARKER, start).map_or(start, |(_, end)| end);
+    Ok(format!(
+        "{}\n\n{}\n{}{}",
+        &readme[..start],
+        table,
+        END_MARKER,
+        &readme[end..]
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::history::Entry;
+
+    fn rows() -> Vec<Row> {
+        let answers = Answers::parse("part1: 24000\n");
+        let history = History::new(vec![
+            Entry {
+                run: 1,
+                day: 1,
+                part: 1,
+                elapsed: Duration::from_micros(50),
+            },
+            Entry {
+                run: 2,
+                day: 1,
+                part: 1,
+                elapsed: Duration::from_micros(40),
+            },
+        ]);
+        vec![
+            Row::new(1, &answers, &history),
+            Row::new(2, &Answers::default(), &history),
+        ]
+    }
+
+    #[test]
+    fn test_row() {
+        let rows = rows();
+        assert_eq!(rows[0].stars, [true, false]);
+        assert_eq!(rows[0].elapsed, [Some(Duration::from_micros(40)), None]);
+        assert!(rows[1].is_empty());
+    }
+
+    #[test]
+    fn test_render_table() {
+        assert_eq!(
+            render_table(2022, &rows()[..1]),
+            concat!(
+                "## 2022 Results\n\n⭐ 1 of 50\n\n",
+                "| Day | Part 1 | Part 2 |\n| :---: | :---: | :---: |\n",
+                "| [Day 1](https://adventofcode.com/2022/day/1) | ⭐ `40.00µs` |  |\n"
+            )
+        );
+    }
+
+    #[test]
+    fn test_replace_table() {
+        let readme = format!("# AoC\n\n{}\n\n---\n", START_MARKER);
+        let once = replace_table(&readme, "| table |\n").unwrap();
+        assert_eq!(
+            once,
+            format!(
+                "# AoC\n\n{}\n\n| table |\n\n{}\n\n---\n",
+                START_MARKER, END_MARKER
+            )
+        );
+        assert_eq!(replace_table(&once, "| table |\n").unwrap(), once);
+        assert!(replace_table(&once, "| new |\n")
+            .unwrap()
+            .contains("\n| new |\n\n"));
+        assert!(replace_table("# AoC\n", "").is_err());
+    }
+
+    #[test]
+    fn test_replace_table_ignores_quoted_markers() {
+        let readme = format!(
+            "# AoC\n\n{}\n\n## Usage\n\nWrites between `{}` and `{}`.\n",
+            START_MARKER, START_MARKER, END_MARKER
+        );
+        assert_eq!(
+            replace_table(&readme, "| table |\n").unwrap(),
+            format!(
+                "# AoC\n\n{}\n\n| table |\n\n{}\n\n## Usage\n\nWrites between `{}` and `{}`.\n",
+                START_MARKER, END_MARKER, START_MARKER, END_MARKER
+            )
+        );
+    }
+
+    #[test]
+    fn test_replace_table_in_readme() {
+        let readme = include_str!("../README.md");
+        let table = render_table(2022, &rows());
+        let updated = replace_table(readme, &table).unwrap();
+
+        // every line of the README is still there, in order, and the table was added.
+        let mut lines = updated.lines();
+        for line in readme.lines().filter(|line| !line.is_empty()) {
+            assert!(lines.any(|l| l == line), "lost README line {:?}", line);
+        }
+        assert!(updated.contains(&table));
+        assert_eq!(replace_table(&updated, &table).unwrap(), updated);
+    }
+}
@@ -0,0 +1,469 @@
+/*
+ * Machine-readable reports for the all-days runner (`cargo all --format json|csv`).
+ */
+use crate::alloc::AllocStats;
+use crate::answers::Verdict;
+use crate::bench::Stats;
+use crate::{ParseResult, PartResult, ReadError};
+use std::str::FromStr;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            "csv" => Ok(Self::Csv),
+            _ => Err(format!(
+                "unknown format \"{}\", expected json, csv or text",
+                s
+            )),
+        }
+    }
+}
+
+/// The result of running one part, with its benchmark statistics and verdict.
+#[derive(Debug, Clone)]
+pub struct PartReport {
+    pub result: PartResult,
+    /// Only present if the runner was started with `--bench`.
+    pub bench: Option<Stats>,
+    pub verdict: Verdict,
+}
+
+/// Whether a day could be run. Only completed days have parts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DayStatus {
+    Completed,
+    /// The day is not registered in `days`.
+    NotSolved,
+    Input(ReadError),
+    /// The solution panicked, with the panic message or the captured stderr of the day.
+    Panicked(String),
+    /// The day was killed after running longer than the timeout.
+    TimedOut(Duration),
+    /// The binary of the day could not be built, with the compiler errors. See `build`.
+    BuildFailed(String),
+}
+
+impl DayStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DayStatus::Completed => "completed",
+            DayStatus::NotSolved => "not_solved",
+            DayStatus::Input(ReadError::Missing(_) | ReadError::OldLocation { .. }) => {
+                "input_missing"
+            }
+            DayStatus::Input(ReadError::Empty(_)) => "input_empty",
+            DayStatus::Input(ReadError::NotUtf8(_)) => "input_not_utf8",
+            DayStatus::Input(ReadError::Io(..)) => "input_error",
+            DayStatus::Panicked(_) => "panicked",
+            DayStatus::TimedOut(_) => "timed_out",
+            DayStatus::BuildFailed(_) => "build_failed",
+        }
+    }
+
+    /// Whether the day failed in a way that should fail the run.
+    pub fn is_failure(&self) -> bool {
+        matches!(
+            self,
+            DayStatus::Panicked(_) | DayStatus::TimedOut(_) | DayStatus::BuildFailed(_)
+        )
+    }
+
+    fn message(&self) -> Option<String> {
+        match self {
+            DayStatus::Panicked(message) | DayStatus::BuildFailed(message) => Some(message.clone()),
+            DayStatus::TimedOut(timeout) => Some(format!("timed out after {:.2?}", timeout)),
+            _ => None,
+        }
+    }
+}
+
+/// The results of running one day.
+#[derive(Debug, Clone)]
+pub struct DayReport {
+    pub day: u8,
+    pub status: DayStatus,
+    /// Only present for two-phase days, see `days`.
+    pub parse: Option<ParseResult>,
+    pub parts: Vec<PartReport>,
+}
+
+impl DayReport {
+    pub fn new(day: u8, status: DayStatus) -> Self {
+        Self {
+            day,
+            status,
+            parse: None,
+            parts: vec![],
+        }
+    }
+}
+
+fn status(result: &PartResult) -> &'static str {
+    if result.is_solved() {
+        "solved"
+    } else {
+        "unsolved"
+    }
+}
+
+fn json_string(val: &str) -> String {
+    let mut out = String::with_capacity(val.len() + 2);
+    out.push('"');
+    for c in val.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn csv_field(val: &str) -> String {
+    if val.contains(['"', ',', '\n', '\r']) {
+        format!("\"{}\"", val.replace('"', "\"\""))
+    } else {
+        val.to_string()
+    }
+}
+
+fn json_allocs(allocs: &Option<AllocStats>) -> String {
+    match allocs {
+        Some(allocs) => format!(
+            ",\"allocs\":{{\"allocations\":{},\"bytes\":{},\"peak_bytes\":{}}}",
+            allocs.allocations, allocs.bytes, allocs.peak
+        ),
+        None => String::new(),
+    }
+}
+
+pub fn to_json(days: &[DayReport], total: Duration) -> String {
+    let days = days
+        .iter()
+        .map(|day| {
+            let parts = day
+                .parts
+                .iter()
+                .map(|PartReport { result, bench, verdict }| {
+                    let bench = match bench {
+                        Some(stats) => format!(
+                            ",\"bench\":{{\"runs\":{},\"cold_ns\":{},\"min_ns\":{},\"median_ns\":{},\"mean_ns\":{},\"stddev_ns\":{}}}",
+                            stats.runs,
+                            stats.cold.as_nanos(),
+                            stats.min.as_nanos(),
+                            stats.median.as_nanos(),
+                            stats.mean.as_nanos(),
+                            stats.stddev.as_nanos()
+                        ),
+                        None => String::new(),
+                    };
+                    format!(
+                        "{{\"part\":{},\"status\":\"{}\",\"answer\":{},\"elapsed_ns\":{},\"verdict\":\"{}\"{}{}}}",
+                        result.part,
+                        status(result),
+                        result
+                            .answer
+                            .as_deref()
+                            .map_or("null".to_string(), json_string),
+                        result.elapsed.as_nanos(),
+                        verdict.as_str(),
+                        bench,
+                        json_allocs(&result.allocs)
+                    )
+                })
+                .collect::<Vec<_>>();
+            let message = match day.status.message() {
+                Some(message) => format!(",\"message\":{}", json_string(&message)),
+                None => String::new(),
+            };
+            let parse = match &day.parse {
+                Some(parse) => format!(
+                    ",\"parse\":{{\"elapsed_ns\":{}{}}}",
+                    parse.elapsed.as_nanos(),
+                    json_allocs(&parse.allocs)
+                ),
+                None => String::new(),
+            };
+            format!(
+                "{{\"day\":{},\"status\":\"{}\"{}{},\"parts\":[{}]}}",
+                day.day,
+                day.status.as_str(),
+                message,
+                parse,
+                parts.join(",")
+            )
+        })
+        .collect::<Vec<_>>();
+
+    format!(
+        "{{\"days\":[{}],\"total_ns\":{}}}",
+        days.join(","),
+        total.as_nanos()
+    )
+}
+
+const BENCH_COLUMNS: &str = ",runs,cold_ns,min_ns,median_ns,mean_ns,stddev_ns";
+const ALLOC_COLUMNS: &str = ",allocations,allocated_bytes,peak_bytes";
+
+/// Formats an optional group of columns, or as many empty columns as `header` has.
+fn csv_columns(header: &str, values: Option<Vec<String>>) -> String {
+    match values {
+        Some(values) => format!(",{}", values.join(",")),
+        None => ",".repeat(header.matches(',').count()),
+    }
+}
+
+/// Benchmark and allocation columns are only added if any part has them.
+pub fn to_csv(days: &[DayReport], total: Duration) -> String {
+    let parts = || days.iter().flat_map(|day| &day.parts);
+    let with_bench = parts().any(|part| part.bench.is_some());
+    let with_allocs = parts().any(|part| part.result.allocs.is_some())
+        || days
+            .iter()
+            .any(|day| day.parse.as_ref().is_some_and(|p| p.allocs.is_some()));
+
+    let extra_columns = |bench: Option<&Stats>, allocs: Option<&AllocStats>| {
+        let mut out = String::new();
+        if with_bench {
+            out.push_str(&csv_columns(
+                BENCH_COLUMNS,
+                bench.map(|stats| {
+                    vec![
+                        stats.runs.to_string(),
+                        stats.cold.as_nanos().to_string(),
+                        stats.min.as_nanos().to_string(),
+                        stats.median.as_nanos().to_string(),
+                        stats.mean.as_nanos().to_string(),
+                        stats.stddev.as_nanos().to_string(),
+                    ]
+                }),
+            ));
+        }
+        if with_allocs {
+            out.push_str(&csv_columns(
+                ALLOC_COLUMNS,
+                allocs.map(|a| {
+                    vec![
+                        a.allocations.to_string(),
+                        a.bytes.to_string(),
+                        a.peak.to_string(),
+                    ]
+                }),
+            ));
+        }
+        out
+    };
+
+    let mut out = String::from("day,part,status,answer,elapsed_ns,verdict");
+    if with_bench {
+        out.push_str(BENCH_COLUMNS);
+    }
+    if with_allocs {
+        out.push_str(ALLOC_COLUMNS);
+    }
+    out.push('\n');
+
+    for day in days {
+        // days that could not run get a single row without a part.
+        if matches!(day.status, DayStatus::Input(_)) || day.status.is_failure() {
+            out.push_str(&format!(
+                "{},,{},,,{}\n",
+                day.day,
+                day.status.as_str(),
+                extra_columns(None, None)
+            ));
+        }
+        // the parse step of two-phase days is a row of its own, with "parse" as its part.
+        if let Some(parse) = &day.parse {
+            out.push_str(&format!(
+                "{},parse,parsed,,{},{}\n",
+                day.day,
+                parse.elapsed.as_nanos(),
+                extra_columns(None, parse.allocs.as_ref())
+            ));
+        }
+        for PartReport {
+            result,
+            bench,
+            verdict,
+        } in &day.parts
+        {
+            out.push_str(&format!(
+                "{},{},{},{},{},{}{}\n",
+                day.day,
+                result.part,
+                status(result),
+                csv_field(result.answer.as_deref().unwrap_or_default()),
+                result.elapsed.as_nanos(),
+                verdict.as_str(),
+                extra_columns(bench.as_ref(), result.allocs.as_ref())
+            ));
+        }
+    }
+
+    out.push_str(&format!(
+        "total,,,,{},{}\n",
+        total.as_nanos(),
+        extra_columns(None, None)
+    ));
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn part(part: u8, answer: Option<&str>, nanos: u64, verdict: Verdict) -> PartReport {
+        PartReport {
+            result: PartResult {
+                part,
+                answer: answer.map(String::from),
+                elapsed: Duration::from_nanos(nanos),
+                allocs: None,
+            },
+            bench: None,
+            verdict,
+        }
+    }
+
+    fn example() -> Vec<DayReport> {
+        vec![
+            DayReport {
+                day: 5,
+                status: DayStatus::Completed,
+                parse: Some(ParseResult {
+                    elapsed: Duration::from_nanos(300),
+                    allocs: None,
+                }),
+                parts: vec![
+                    part(1, Some("CMZ"), 1500, Verdict::Correct),
+                    part(2, None, 20, Verdict::Unknown),
+                ],
+            },
+            DayReport::new(6, DayStatus::NotSolved),
+            DayReport::new(7, DayStatus::Input(ReadError::Missing("07.txt".into()))),
+            DayReport::new(8, DayStatus::Panicked("boom \"here\"".into())),
+        ]
+    }
+
+    #[test]
+    fn test_to_json() {
+        assert_eq!(
+            to_json(&example(), Duration::from_nanos(1520)),
+            concat!(
+                r#"{"days":[{"day":5,"status":"completed","parse":{"elapsed_ns":300},"parts":["#,
+                r#"{"part":1,"status":"solved","answer":"CMZ","elapsed_ns":1500,"verdict":"correct"},"#,
+                r#"{"part":2,"status":"unsolved","answer":null,"elapsed_ns":20,"verdict":"unknown"}]},"#,
+                r#"{"day":6,"status":"not_solved","parts":[]},"#,
+                r#"{"day":7,"status":"input_missing","parts":[]},"#,
+                r#"{"day":8,"status":"panicked","message":"boom \"here\"","parts":[]}],"total_ns":1520}"#
+            )
+        );
+    }
+
+    #[test]
+    fn test_to_csv() {
+        assert_eq!(
+            to_csv(&example(), Duration::from_nanos(1520)),
+            concat!(
+                "day,part,status,answer,elapsed_ns,verdict\n",
+                "5,parse,parsed,,300,\n",
+                "5,1,solved,CMZ,1500,correct\n",
+                "5,2,unsolved,,20,unknown\n",
+                "7,,input_missing,,,\n",
+                "8,,panicked,,,\n",
+                "total,,,,1520,\n"
+            )
+        );
+    }
+
+    #[test]
+    fn test_bench_columns() {
+        let mut days = example();
+        days[0].parts[0].bench = Some(Stats {
+            part: 1,
+            runs: 10,
+            cold: Duration::from_nanos(3000),
+            min: Duration::from_nanos(1400),
+            median: Duration::from_nanos(1500),
+            mean: Duration::from_nanos(1550),
+            stddev: Duration::from_nanos(80),
+        });
+
+        assert!(to_json(&days, Duration::from_nanos(1520)).contains(
+            r#""verdict":"correct","bench":{"runs":10,"cold_ns":3000,"min_ns":1400,"median_ns":1500,"mean_ns":1550,"stddev_ns":80}}"#
+        ));
+        assert_eq!(
+            to_csv(&days, Duration::from_nanos(1520)),
+            concat!(
+                "day,part,status,answer,elapsed_ns,verdict,runs,cold_ns,min_ns,median_ns,mean_ns,stddev_ns\n",
+                "5,parse,parsed,,300,,,,,,,\n",
+                "5,1,solved,CMZ,1500,correct,10,3000,1400,1500,1550,80\n",
+                "5,2,unsolved,,20,unknown,,,,,,\n",
+                "7,,input_missing,,,,,,,,,\n",
+                "8,,panicked,,,,,,,,,\n",
+                "total,,,,1520,,,,,,,\n"
+            )
+        );
+    }
+
+    #[test]
+    fn test_alloc_columns() {
+        let mut days = example();
+        days[0].parts[1].result.allocs = Some(AllocStats {
+            allocations: 12,
+            bytes: 4096,
+            peak: 1024,
+        });
+        days[0].parse.as_mut().unwrap().allocs = Some(AllocStats {
+            allocations: 1,
+            bytes: 64,
+            peak: 64,
+        });
+
+        let json = to_json(&days, Duration::from_nanos(1520));
+        assert!(json.contains(
+            r#""parse":{"elapsed_ns":300,"allocs":{"allocations":1,"bytes":64,"peak_bytes":64}}"#
+        ));
+        assert!(json.contains(
+            r#""verdict":"unknown","allocs":{"allocations":12,"bytes":4096,"peak_bytes":1024}}"#
+        ));
+        assert_eq!(
+            to_csv(&days, Duration::from_nanos(1520)),
+            concat!(
+                "day,part,status,answer,elapsed_ns,verdict,allocations,allocated_bytes,peak_bytes\n",
+                "5,parse,parsed,,300,,1,64,64\n",
+                "5,1,solved,CMZ,1500,correct,,,\n",
+                "5,2,unsolved,,20,unknown,12,4096,1024\n",
+                "7,,input_missing,,,,,,\n",
+                "8,,panicked,,,,,,\n",
+                "total,,,,1520,,,,\n"
+            )
+        );
+    }
+
+    #[test]
+    fn test_escaping() {
+        assert_eq!(json_string("#.\n\"a\"\\"), r##""#.\n\"a\"\\""##);
+        assert_eq!(csv_field("#.\n.#"), "\"#.\n.#\"");
+        assert_eq!(csv_field("a\"b"), "\"a\"\"b\"");
+        assert_eq!(csv_field("1,2"), "\"1,2\"");
+    }
+}
@@ -0,0 +1,137 @@
+/*
+ * Which days the all-days runner should run, e.g. `cargo all 3..=7 --skip 5`.
+ */
+use crate::days;
+use std::ops::RangeInclusive;
+
+pub const ALL_DAYS: RangeInclusive<u8> = 1..=25;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Selection {
+    range: RangeInclusive<u8>,
+    only: Option<Vec<u8>>,
+    skip: Vec<u8>,
+    solved_only: bool,
+}
+
+impl Default for Selection {
+    fn default() -> Self {
+        Self {
+            range: ALL_DAYS,
+            only: None,
+            skip: vec![],
+            solved_only: false,
+        }
+    }
+}
+
+fn parse_day(s: &str) -> Result<u8, String> {
+    match s.trim().parse() {
+        Ok(day) if ALL_DAYS.contains(&day) => Ok(day),
+        _ => Err(format!("\"{}\" is not a day between 1 and 25", s)),
+    }
+}
+
+/// Parses `N`, `A..B` or `A..=B`. Either bound of a range may be left out.
+pub fn parse_range(s: &str) -> Result<RangeInclusive<u8>, String> {
+    let (start, end) = match s.split_once("..") {
+        Some(bounds) => bounds,
+        None => {
+            let day = parse_day(s)?;
+            return Ok(day..=day);
+        }
+    };
+
+    let start = match start {
+        "" => *ALL_DAYS.start(),
+        start => parse_day(start)?,
+    };
+    let end = match end.strip_prefix('=') {
+        Some("") => return Err(format!("\"{}\" is missing an end", s)),
+        Some(end) => parse_day(end)?,
+        None if end.is_empty() => *ALL_DAYS.end(),
+        None => parse_day(end)?
+            .checked_sub(1)
+            .filter(|&end| end >= start)
+            .ok_or_else(|| format!("\"{}\" is an empty range", s))?,
+    };
+    Ok(start..=end)
+}
+
+/// Parses a comma-separated list of days, e.g. `5,11`.
+fn parse_list(s: &str) -> Result<Vec<u8>, String> {
+    s.split(',').map(parse_day).collect()
+}
+
+impl Selection {
+    /// Reads `--only <days>`, `--skip <days>`, `--solved-only` and an optional range.
+    /// Must be called after all other options were read, because the range is a free argument.
+    pub fn from_args(args: &mut pico_args::Arguments) -> Result<Self, pico_args::Error> {
+        let only = args.values_from_fn("--only", parse_list)?;
+        let skip = args.values_from_fn("--skip", parse_list)?;
+        Ok(Self {
+            only: if only.is_empty() {
+                None
+            } else {
+                Some(only.concat())
+            },
+            skip: skip.concat(),
+            solved_only: args.contains("--solved-only"),
+            range: args.opt_free_from_fn(parse_range)?.unwrap_or(ALL_DAYS),
+        })
+    }
+
+    pub fn contains(&self, day: u8) -> bool {
+        self.range.contains(&day)
+            && self.only.as_ref().is_none_or(|only| only.contains(&day))
+            && !self.skip.contains(&day)
+            && (!self.solved_only || days::get(day).is_some())
+    }
+
+    pub fn days(&self) -> impl Iterator<Item = u8> + '_ {
+        ALL_DAYS.filter(|day| self.contains(*day))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn select(args: &[&str]) -> Result<Vec<u8>, pico_args::Error> {
+        let mut args = pico_args::Arguments::from_vec(args.iter().map(|a| a.into()).collect());
+        let selection = Selection::from_args(&mut args)?;
+        Ok(selection.days().collect())
+    }
+
+    #[test]
+    fn test_parse_range() {
+        assert_eq!(parse_range("3..=7"), Ok(3..=7));
+        assert_eq!(parse_range("3..7"), Ok(3..=6));
+        assert_eq!(parse_range("20.."), Ok(20..=25));
+        assert_eq!(parse_range("..=2"), Ok(1..=2));
+        assert_eq!(parse_range("12"), Ok(12..=12));
+        assert!(parse_range("0..3").is_err());
+        assert!(parse_range("3..=26").is_err());
+        assert!(parse_range("3..=").is_err());
+        assert!(parse_range("x").is_err());
+        assert_eq!(parse_range("..1"), Err("\"..1\" is an empty range".into()));
+        assert!(parse_range("1..1").is_err());
+        assert!(parse_range("7..3").is_err());
+    }
+
+    #[test]
+    fn test_selection() {
+        assert_eq!(select(&[]).unwrap(), (1..=25).collect::<Vec<_>>());
+        assert_eq!(select(&["3..=7"]).unwrap(), vec![3, 4, 5, 6, 7]);
+        assert_eq!(select(&["--only", "5,11"]).unwrap(), vec![5, 11]);
+        assert_eq!(
+            select(&["--skip", "4", "--skip", "5,6", "3..=7"]).unwrap(),
+            vec![3, 7]
+        );
+        assert_eq!(
+            select(&["--solved-only"]).unwrap(),
+            days::ALL.iter().map(|s| s.day).collect::<Vec<_>>()
+        );
+        assert!(select(&["--only", "5,x"]).is_err());
+    }
+}
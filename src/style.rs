@@ -0,0 +1,108 @@
+/*
+ * Terminal styling. Escape codes are only written if stdout is a terminal and `NO_COLOR`
+ * is not set, unless overridden with `--color always|never`.
+ */
+use std::env;
+use std::io::{self, IsTerminal};
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+const BOLD: &str = "\x1b[1m";
+const ITALIC: &str = "\x1b[3m";
+const RESET: &str = "\x1b[0m";
+
+static ENABLED: OnceLock<bool> = OnceLock::new();
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ColorChoice {
+    /// Style output if stdout is a terminal and `NO_COLOR` is not set.
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
+impl FromStr for ColorChoice {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" => Ok(Self::Auto),
+            "always" => Ok(Self::Always),
+            "never" => Ok(Self::Never),
+            _ => Err(format!(
+                "unknown color choice \"{}\", expected auto, always or never",
+                s
+            )),
+        }
+    }
+}
+
+impl ColorChoice {
+    /// Reads `--color <auto|always|never>` and applies it, see `set_color`.
+    pub fn from_args(args: &mut pico_args::Arguments) -> Result<Self, pico_args::Error> {
+        let choice = args.opt_value_from_str("--color")?.unwrap_or_default();
+        set_color(choice);
+        Ok(choice)
+    }
+
+    fn resolve(self, no_color: bool, is_terminal: bool) -> bool {
+        match self {
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+            ColorChoice::Auto => !no_color && is_terminal,
+        }
+    }
+}
+
+/// Decides whether output is styled. Only the first call has an effect, so this has to
+/// happen before anything is printed.
+pub fn set_color(choice: ColorChoice) {
+    let no_color = env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty());
+    ENABLED.get_or_init(|| choice.resolve(no_color, io::stdout().is_terminal()));
+}
+
+pub fn enabled() -> bool {
+    set_color(ColorChoice::Auto);
+    ENABLED.get().copied().unwrap_or_default()
+}
+
+fn code(code: &'static str) -> &'static str {
+    if enabled() {
+        code
+    } else {
+        ""
+    }
+}
+
+pub fn bold() -> &'static str {
+    code(BOLD)
+}
+
+pub fn italic() -> &'static str {
+    code(ITALIC)
+}
+
+pub fn reset() -> &'static str {
+    code(RESET)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve() {
+        assert!(ColorChoice::Auto.resolve(false, true));
+        assert!(!ColorChoice::Auto.resolve(true, true));
+        assert!(!ColorChoice::Auto.resolve(false, false));
+        assert!(ColorChoice::Always.resolve(true, false));
+        assert!(!ColorChoice::Never.resolve(false, true));
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("never".parse(), Ok(ColorChoice::Never));
+        assert!("sometimes".parse::<ColorChoice>().is_err());
+    }
+}
@@ -0,0 +1,240 @@
+/*
+ * Submitted answers per day, stored in `src/submissions/YYYY/DD.txt`:
+ *
+ *     part1 too_high: 5231
+ *     part1 correct: 4961
+ *
+ * `cargo submit` appends a line for every answer that got a verdict and refuses to
+ * submit answers that are known to be wrong.
+ */
+use crate::{data_file, escape_record, src_dir, unescape_record, YEAR};
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint in which direction.
+    Wrong,
+    /// Submitted too recently after a wrong answer, the answer was not checked.
+    Wait,
+}
+
+impl Outcome {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Outcome::Correct => "correct",
+            Outcome::TooHigh => "too_high",
+            Outcome::TooLow => "too_low",
+            Outcome::Wrong => "wrong",
+            Outcome::Wait => "wait",
+        }
+    }
+
+    fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "correct" => Some(Outcome::Correct),
+            "too_high" => Some(Outcome::TooHigh),
+            "too_low" => Some(Outcome::TooLow),
+            "wrong" => Some(Outcome::Wrong),
+            "wait" => Some(Outcome::Wait),
+            _ => None,
+        }
+    }
+
+    /// Reads the verdict from the response to a submission. Returns `None` for unknown responses.
+    pub fn from_response(response: &str) -> Option<Self> {
+        let response = response.to_lowercase();
+        if response.contains("that's the right answer") {
+            Some(Outcome::Correct)
+        } else if response.contains("too recently") {
+            Some(Outcome::Wait)
+        } else if response.contains("too high") {
+            Some(Outcome::TooHigh)
+        } else if response.contains("too low") {
+            Some(Outcome::TooLow)
+        } else if response.contains("not the right answer") {
+            Some(Outcome::Wrong)
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    pub part: u8,
+    pub answer: String,
+    pub outcome: Outcome,
+}
+
+impl Submission {
+    fn parse(line: &str) -> Option<Self> {
+        let (key, answer) = line.split_once(':')?;
+        let (part, outcome) = key.split_once(' ')?;
+        Some(Self {
+            part: part.strip_prefix("part")?.parse().ok()?,
+            answer: unescape_record(answer.trim()),
+            outcome: Outcome::from_str(outcome)?,
+        })
+    }
+}
+
+impl std::fmt::Display for Submission {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "part{} {}: {}",
+            self.part,
+            self.outcome.as_str(),
+            escape_record(&self.answer)
+        )
+    }
+}
+
+fn submissions_path(day: u8) -> PathBuf {
+    src_dir().join(data_file("submissions", YEAR, day))
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Submissions {
+    entries: Vec<Submission>,
+}
+
+impl Submissions {
+    pub fn parse(contents: &str) -> Self {
+        Self {
+            entries: contents.lines().filter_map(Submission::parse).collect(),
+        }
+    }
+
+    /// Loads the submissions of `day`. A missing file means that nothing was submitted yet.
+    pub fn load(day: u8) -> io::Result<Self> {
+        match fs::read_to_string(submissions_path(day)) {
+            Ok(contents) => Ok(Self::parse(&contents)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Records `submission` in memory and in the file of `day`.
+    pub fn append(&mut self, day: u8, submission: Submission) -> io::Result<()> {
+        let path = submissions_path(day);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        writeln!(file, "{}", submission)?;
+        self.entries.push(submission);
+        Ok(())
+    }
+
+    /// Checks whether `answer` may be submitted for `part`, or why not.
+    /// Numeric answers are also checked against earlier answers that were too high or too low.
+    pub fn check(&self, part: u8, answer: &str) -> Result<(), String> {
+        if answer.contains('\n') {
+            return Err("the answer spans multiple lines, submit it by hand".into());
+        }
+        let number = answer.parse::<i64>().ok();
+
+        for entry in self.entries.iter().filter(|e| e.part == part) {
+            let bound = entry.answer.parse::<i64>().ok();
+            let reason = match (entry.outcome, number, bound) {
+                (Outcome::Correct, ..) => {
+                    format!("part {} was already solved with {}", part, entry.answer)
+                }
+                (Outcome::Wait, ..) => continue,
+                (outcome, ..) if entry.answer == answer => {
+                    format!(
+                        "it was already submitted and was {}",
+                        outcome.as_str().replace('_', " ")
+                    )
+                }
+                (Outcome::TooHigh, Some(n), Some(high)) if n >= high => {
+                    format!("{} was too high", entry.answer)
+                }
+                (Outcome::TooLow, Some(n), Some(low)) if n <= low => {
+                    format!("{} was too low", entry.answer)
+                }
+                _ => continue,
+            };
+            return Err(reason);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_outcome_from_response() {
+        let response = |text| Outcome::from_response(text);
+        assert_eq!(
+            response("That's the right answer! You are one gold star closer."),
+            Some(Outcome::Correct)
+        );
+        assert_eq!(
+            response("That's not the right answer; your answer is too high."),
+            Some(Outcome::TooHigh)
+        );
+        assert_eq!(
+            response("That's not the right answer; your answer is too low."),
+            Some(Outcome::TooLow)
+        );
+        assert_eq!(
+            response("That's not the right answer. If you're stuck, ..."),
+            Some(Outcome::Wrong)
+        );
+        assert_eq!(
+            response("You gave an answer too recently; you have 4m 2s left to wait."),
+            Some(Outcome::Wait)
+        );
+        assert_eq!(response("Did you already complete it?"), None);
+    }
+
+    #[test]
+    fn test_parse_and_format() {
+        let contents = "part1 too_high: 5231\npart2 wait: 12\npart2 wrong: a\\nb\n";
+        let submissions = Submissions::parse(contents);
+        assert_eq!(submissions.entries.len(), 3);
+        assert_eq!(
+            submissions.entries[0],
+            Submission {
+                part: 1,
+                answer: "5231".into(),
+                outcome: Outcome::TooHigh,
+            }
+        );
+        assert_eq!(submissions.entries[2].answer, "a\nb");
+
+        let formatted = submissions
+            .entries
+            .iter()
+            .map(|s| format!("{}\n", s))
+            .collect::<String>();
+        assert_eq!(formatted, contents);
+    }
+
+    #[test]
+    fn test_check() {
+        let submissions = Submissions::parse(
+            "part1 too_high: 500\npart1 too_low: 100\npart1 wait: 300\npart2 wrong: CMZ\n",
+        );
+        assert_eq!(submissions.check(1, "300"), Ok(()));
+        assert!(submissions.check(1, "500").is_err());
+        assert!(submissions.check(1, "800").is_err());
+        assert!(submissions.check(1, "99").is_err());
+        assert!(submissions.check(2, "CMZ").is_err());
+        assert_eq!(submissions.check(2, "MCD"), Ok(()));
+        assert!(submissions.check(2, "M\nC").is_err());
+
+        let solved = Submissions::parse("part2 correct: MCD\n");
+        assert!(solved.check(2, "MCD").is_err());
+        assert_eq!(solved.check(1, "MCD"), Ok(()));
+    }
+}
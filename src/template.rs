@@ -0,0 +1,157 @@
+/*
+ * Placeholders for `cargo scaffold` templates:
+ *
+ *     {{day}}          5
+ *     {{day_padded}}   05
+ *     {{year}}         2022
+ *     {{answer_type}}  u32, see `ANSWER_TYPES`
+ *     {{part_one_expected}}
+ *                      Some(24000), the expected answer to the example of part one, or None
+ */
+
+/// The answer types that scaffold accepts for `{{answer_type}}`.
+pub const ANSWER_TYPES: &[&str] = &["u32", "u64", "i32", "i64", "usize", "String"];
+pub const DEFAULT_ANSWER_TYPE: &str = "u32";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Vars {
+    pub day: u8,
+    pub year: u16,
+    pub answer_type: String,
+    /// The answer to the example of part one, if it is known.
+    pub part_one_answer: Option<String>,
+}
+
+impl Vars {
+    fn get(&self, name: &str) -> Option<String> {
+        match name {
+            "day" => Some(self.day.to_string()),
+            "day_padded" => Some(format!("{:02}", self.day)),
+            "year" => Some(self.year.to_string()),
+            "answer_type" => Some(self.answer_type.clone()),
+            "part_one_expected" => Some(match &self.part_one_answer {
+                Some(answer) => {
+                    expected(&self.answer_type, answer).unwrap_or_else(|_| "None".into())
+                }
+                None => "None".into(),
+            }),
+            _ => None,
+        }
+    }
+}
+
+pub fn parse_answer_type(s: &str) -> Result<String, String> {
+    if ANSWER_TYPES.contains(&s) {
+        Ok(s.to_string())
+    } else {
+        Err(format!(
+            "unknown answer type \"{}\", expected one of {}",
+            s,
+            ANSWER_TYPES.join(", ")
+        ))
+    }
+}
+
+/// The expression of `answer` as an `Option<answer_type>`, or an error if it does not fit the type.
+pub fn expected(answer_type: &str, answer: &str) -> Result<String, String> {
+    let fits = match answer_type {
+        "u32" => answer.parse::<u32>().is_ok(),
+        "u64" => answer.parse::<u64>().is_ok(),
+        "i32" => answer.parse::<i32>().is_ok(),
+        "i64" => answer.parse::<i64>().is_ok(),
+        "usize" => answer.parse::<usize>().is_ok(),
+        "String" => return Ok(format!("Some({:?}.to_string())", answer)),
+        _ => false,
+    };
+    if fits {
+        Ok(format!("Some({})", answer))
+    } else {
+        Err(format!("\"{}\" is not a {}", answer, answer_type))
+    }
+}
+
+/// Replaces the placeholders in `template`. Unknown placeholders are an error, to catch typos.
+pub fn render(template: &str, vars: &Vars) -> Result<String, String> {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        out.push_str(&rest[..start]);
+        let end = rest[start..]
+            .find("}}")
+            .ok_or_else(|| "unclosed placeholder \"{{\"".to_string())?;
+        let name = rest[start + 2..start + end].trim();
+        match vars.get(name) {
+            Some(value) => out.push_str(&value),
+            None => return Err(format!("unknown placeholder \"{{{{{}}}}}\"", name)),
+        }
+        rest = &rest[start + end + 2..];
+    }
+    out.push_str(rest);
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vars() -> Vars {
+        Vars {
+            day: 5,
+            year: 2022,
+            answer_type: "String".into(),
+            part_one_answer: None,
+        }
+    }
+
+    #[test]
+    fn test_render() {
+        assert_eq!(
+            render(
+                "// {{year}} day {{ day }}\nfn part_one() -> Option<{{answer_type}}> {} // {{day_padded}}",
+                &vars()
+            ),
+            Ok("// 2022 day 5\nfn part_one() -> Option<String> {} // 05".into())
+        );
+        assert_eq!(
+            render("no placeholders", &vars()),
+            Ok("no placeholders".into())
+        );
+        assert_eq!(
+            render("{{dya}}", &vars()),
+            Err("unknown placeholder \"{{dya}}\"".into())
+        );
+        assert!(render("{{day", &vars()).is_err());
+    }
+
+    #[test]
+    fn test_part_one_expected() {
+        let mut vars = vars();
+        assert_eq!(render("{{part_one_expected}}", &vars), Ok("None".into()));
+
+        vars.part_one_answer = Some("CMZ".into());
+        assert_eq!(
+            render("{{part_one_expected}}", &vars),
+            Ok("Some(\"CMZ\".to_string())".into())
+        );
+
+        vars.answer_type = "u32".into();
+        assert_eq!(render("{{part_one_expected}}", &vars), Ok("None".into()));
+        vars.part_one_answer = Some("24000".into());
+        assert_eq!(
+            render("{{part_one_expected}}", &vars),
+            Ok("Some(24000)".into())
+        );
+    }
+
+    #[test]
+    fn test_expected() {
+        assert_eq!(expected("i64", "-3"), Ok("Some(-3)".into()));
+        assert_eq!(expected("u32", "-3"), Err("\"-3\" is not a u32".into()));
+    }
+
+    #[test]
+    fn test_parse_answer_type() {
+        assert_eq!(parse_answer_type("u64"), Ok("u64".into()));
+        assert!(parse_answer_type("f32").is_err());
+    }
+}
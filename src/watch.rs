@@ -0,0 +1,115 @@
+/*
+ * Change detection and answer diffs for `cargo watch-day`.
+ */
+use crate::{data_file, src_dir, PartResult, YEAR};
+use std::fs;
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+/// The files that make up a day: its solution, binary, input and example.
+pub fn day_files(day: u8) -> Vec<PathBuf> {
+    let src = src_dir();
+    vec![
+        src.join("days").join(format!("day{:02}.rs", day)),
+        src.join("bin").join(format!("{:02}.rs", day)),
+        src.join(data_file("inputs", YEAR, day)),
+        src.join(data_file("examples", YEAR, day)),
+    ]
+}
+
+/// Polls the modification times of a set of files.
+#[derive(Debug)]
+pub struct Watcher {
+    files: Vec<PathBuf>,
+    /// `None` for files that do not exist (yet).
+    modified: Vec<Option<SystemTime>>,
+}
+
+fn modified_times(files: &[PathBuf]) -> Vec<Option<SystemTime>> {
+    files
+        .iter()
+        .map(|file| fs::metadata(file).and_then(|m| m.modified()).ok())
+        .collect()
+}
+
+impl Watcher {
+    pub fn new(files: Vec<PathBuf>) -> Self {
+        let modified = modified_times(&files);
+        Self { files, modified }
+    }
+
+    /// Returns the files that were created, changed or removed since the last call.
+    pub fn changed(&mut self) -> Vec<&PathBuf> {
+        let modified = modified_times(&self.files);
+        let changed = self
+            .files
+            .iter()
+            .zip(self.modified.iter().zip(&modified))
+            .filter(|(_, (before, after))| before != after)
+            .map(|(file, _)| file)
+            .collect();
+        self.modified = modified;
+        changed
+    }
+}
+
+/// Describes how the answer of a part changed between two runs, e.g. `24000 → 24001`.
+pub fn diff_answer(previous: Option<&PartResult>, current: &PartResult) -> String {
+    let answer = |result: &PartResult| match &result.answer {
+        Some(answer) if answer.contains('\n') => format!("\n{}\n", answer),
+        Some(answer) => answer.clone(),
+        None => "not solved".into(),
+    };
+    match previous {
+        None => answer(current),
+        Some(previous) if previous.answer == current.answer => {
+            format!("{} (unchanged)", answer(current))
+        }
+        Some(previous) => format!("{} → {}", answer(previous), answer(current)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn result(answer: Option<&str>) -> PartResult {
+        PartResult {
+            part: 1,
+            answer: answer.map(String::from),
+            elapsed: Duration::ZERO,
+            allocs: None,
+        }
+    }
+
+    #[test]
+    fn test_diff_answer() {
+        assert_eq!(diff_answer(None, &result(Some("CMZ"))), "CMZ");
+        assert_eq!(
+            diff_answer(Some(&result(Some("CMZ"))), &result(Some("CMZ"))),
+            "CMZ (unchanged)"
+        );
+        assert_eq!(
+            diff_answer(Some(&result(None)), &result(Some("MCD"))),
+            "not solved → MCD"
+        );
+    }
+
+    #[test]
+    fn test_watcher() {
+        let dir = std::env::temp_dir().join(format!("aoc-watch-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let file = dir.join("01.txt");
+
+        let mut watcher = Watcher::new(vec![file.clone()]);
+        assert!(watcher.changed().is_empty());
+
+        fs::write(&file, "1000").unwrap();
+        assert_eq!(watcher.changed(), vec![&file]);
+        assert!(watcher.changed().is_empty());
+
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(watcher.changed(), vec![&file]);
+    }
+}